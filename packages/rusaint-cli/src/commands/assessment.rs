@@ -3,28 +3,51 @@ use std::{path::Path, sync::Arc};
 use clap::Subcommand;
 use rusaint::{
     USaintSession, application::lecture_assessment::LectureAssessmentApplication,
-    client::USaintClientBuilder,
+    client::USaintClientBuilder, model::LectureCode,
 };
 
 use crate::{
+    current_semester,
     output::{OutputFormat, write_output},
-    types::SemesterType,
+    types::SemesterArg,
 };
 
 #[derive(Subcommand)]
 pub enum AssessmentCommands {
     /// 강의평가 검색
     Find {
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
         /// 강의명 필터
         #[arg(long)]
         lecture_name: Option<String>,
         /// 과목 코드 필터
         #[arg(long)]
-        lecture_code: Option<u32>,
+        lecture_code: Option<LectureCode>,
+        /// 교수명 필터
+        #[arg(long)]
+        professor_name: Option<String>,
+        /// 문항별 결과 포함
+        #[arg(long)]
+        details: bool,
+    },
+    /// 여러 학기의 강의평가를 교수별, 과목별로 집계
+    Aggregate {
+        /// 시작 학년도
+        #[arg(long)]
+        from_year: u32,
+        /// 종료 학년도
+        #[arg(long)]
+        to_year: u32,
+        /// 강의명 필터
+        #[arg(long)]
+        lecture_name: Option<String>,
+        /// 과목 코드 필터
+        #[arg(long)]
+        lecture_code: Option<LectureCode>,
         /// 교수명 필터
         #[arg(long)]
         professor_name: Option<String>,
@@ -49,13 +72,46 @@ pub async fn execute(
             lecture_name,
             lecture_code,
             professor_name,
+            details,
+        } => {
+            let (year, semester) = current_semester::resolve(year, semester).await;
+            if details {
+                let result = app
+                    .find_assessment_details(
+                        year,
+                        semester,
+                        lecture_name.as_deref(),
+                        lecture_code.as_ref(),
+                        professor_name.as_deref(),
+                    )
+                    .await?;
+                write_output(format, output, &result)?;
+            } else {
+                let result = app
+                    .find_assessments(
+                        year,
+                        semester,
+                        lecture_name.as_deref(),
+                        lecture_code.as_ref(),
+                        professor_name.as_deref(),
+                    )
+                    .await?;
+                write_output(format, output, &result)?;
+            }
+        }
+        AssessmentCommands::Aggregate {
+            from_year,
+            to_year,
+            lecture_name,
+            lecture_code,
+            professor_name,
         } => {
             let result = app
-                .find_assessments(
-                    year,
-                    *semester,
+                .aggregate_assessments(
+                    from_year,
+                    to_year,
                     lecture_name.as_deref(),
-                    lecture_code,
+                    lecture_code.as_ref(),
                     professor_name.as_deref(),
                 )
                 .await?;
@@ -1,21 +1,53 @@
 use std::{path::Path, sync::Arc};
 
 use clap::Subcommand;
-use rusaint::{USaintSession, application::chapel::ChapelApplication, client::USaintClientBuilder};
+use rusaint::{
+    USaintSession,
+    application::{
+        chapel::{ChapelApplication, model::ChapelPolicy},
+        student_information::StudentInformationApplication,
+    },
+    client::USaintClientBuilder,
+};
 
 use crate::{
+    current_semester,
     output::{OutputFormat, write_output},
-    types::SemesterType,
+    types::SemesterArg,
 };
 
 #[derive(Subcommand)]
 pub enum ChapelCommands {
     /// 채플 정보 조회
     Information {
+        /// 학년도 (생략하면 현재 학년도)
+        #[arg(short = 'y', long)]
+        year: Option<u32>,
+        #[arg(short = 's', long)]
+        semester: SemesterArg,
+    },
+    /// 채플 출결 분석 및 통과 여부 예측
+    Forecast {
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
+        /// 허용되는 최대 결석 횟수 (기본값: 전체 수업 횟수의 1/3)
+        #[arg(long)]
+        max_absences: Option<u32>,
+        /// 결석 1회로 환산되는 지각 횟수 (0이면 환산하지 않음)
+        #[arg(long, default_value_t = 2)]
+        lates_per_absence: u32,
+        /// 학기 전체 수업 횟수 (기본값: 출결 정보에 포함된 수업 수)
+        #[arg(long)]
+        total_sessions: Option<u32>,
+    },
+    /// 입학 이후 전체 학기 채플 이력 조회
+    History {
+        /// 입학년도 (기본값: 학적 정보의 입학년도)
+        #[arg(long)]
+        apply_year: Option<u32>,
     },
 }
 
@@ -26,13 +58,40 @@ pub async fn execute(
     output: Option<&Path>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut app = USaintClientBuilder::new()
-        .session(session)
+        .session(session.clone())
         .build_into::<ChapelApplication>()
         .await?;
 
     match command {
         ChapelCommands::Information { year, semester } => {
-            let result = app.information(year, *semester).await?;
+            let (year, semester) = current_semester::resolve(year, semester).await;
+            let result = app.information(year, semester).await?;
+            write_output(format, output, &result)?;
+        }
+        ChapelCommands::Forecast {
+            year,
+            semester,
+            max_absences,
+            lates_per_absence,
+            total_sessions,
+        } => {
+            let (year, semester) = current_semester::resolve(year, semester).await;
+            let lates_per_absence = (lates_per_absence > 0).then_some(lates_per_absence);
+            let policy = ChapelPolicy::new(max_absences, lates_per_absence, total_sessions);
+            let result = app.forecast(year, semester, &policy).await?;
+            write_output(format, output, &result)?;
+        }
+        ChapelCommands::History { apply_year } => {
+            let apply_year = match apply_year {
+                Some(apply_year) => apply_year,
+                None => USaintClientBuilder::new()
+                    .session(session)
+                    .build_into::<StudentInformationApplication>()
+                    .await?
+                    .general()?
+                    .apply_year(),
+            };
+            let result = app.history(apply_year).await?;
             write_output(format, output, &result)?;
         }
     }
@@ -8,18 +8,20 @@ use rusaint::{
 };
 
 use crate::{
+    current_semester,
     output::{OutputFormat, write_output},
-    types::SemesterType,
+    types::SemesterArg,
 };
 
 #[derive(Subcommand)]
 pub enum CourseScheduleCommands {
     /// 강의명으로 검색
     ByLecture {
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
         #[arg(short = 'k', long)]
         keyword: String,
         /// 상세 정보 포함 조회
@@ -31,10 +33,11 @@ pub enum CourseScheduleCommands {
     },
     /// 전공별 검색
     Major {
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
         #[arg(short = 'c', long)]
         college: String,
         #[arg(short = 'd', long)]
@@ -48,10 +51,11 @@ pub enum CourseScheduleCommands {
     },
     /// 교양필수 검색
     RequiredElective {
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
         #[arg(short = 'n', long)]
         course_name: String,
         #[arg(long)]
@@ -61,10 +65,11 @@ pub enum CourseScheduleCommands {
     },
     /// 교양선택 검색
     OptionalElective {
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
         #[arg(short = 'n', long)]
         course_name: String,
         #[arg(long)]
@@ -74,10 +79,11 @@ pub enum CourseScheduleCommands {
     },
     /// 채플 강의 검색
     Chapel {
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
         #[arg(short = 'n', long)]
         chapel_name: String,
         #[arg(long)]
@@ -87,10 +93,11 @@ pub enum CourseScheduleCommands {
     },
     /// 교직 검색
     Education {
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
         #[arg(long)]
         detailed: bool,
         #[arg(long)]
@@ -98,10 +105,11 @@ pub enum CourseScheduleCommands {
     },
     /// 연계전공 검색
     ConnectedMajor {
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
         #[arg(short = 'n', long)]
         major_name: String,
         #[arg(long)]
@@ -111,10 +119,11 @@ pub enum CourseScheduleCommands {
     },
     /// 융합전공 검색
     UnitedMajor {
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
         #[arg(short = 'n', long)]
         major_name: String,
         #[arg(long)]
@@ -124,10 +133,11 @@ pub enum CourseScheduleCommands {
     },
     /// 타전공인정 검색
     RecognizedOtherMajor {
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
         #[arg(short = 'c', long)]
         college: String,
         #[arg(short = 'd', long)]
@@ -141,10 +151,11 @@ pub enum CourseScheduleCommands {
     },
     /// 숭실사이버대학교 검색
     Cyber {
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
         #[arg(long)]
         detailed: bool,
         #[arg(long)]
@@ -152,10 +163,11 @@ pub enum CourseScheduleCommands {
     },
     /// 대학원 검색
     Graduated {
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
         #[arg(short = 'c', long)]
         college: String,
         #[arg(short = 'd', long)]
@@ -167,10 +179,11 @@ pub enum CourseScheduleCommands {
     },
     /// 교수명으로 검색
     FindByProfessor {
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
         #[arg(short = 'k', long)]
         keyword: String,
         #[arg(long)]
@@ -353,14 +366,15 @@ pub async fn execute(
         ),
     };
 
+    let (year, semester) = current_semester::resolve(year, semester).await;
     if fetch_syllabus || detailed {
         let lectures = app
-            .find_detailed_lectures(year, *semester, &category, fetch_syllabus)
+            .find_detailed_lectures(year, semester, &category, fetch_syllabus)
             .await?;
         write_output(format, output, &lectures)?;
     } else {
         let lectures: Vec<_> = app
-            .find_lectures(year, *semester, &category)
+            .find_lectures(year, semester, &category)
             .await?
             .collect();
         write_output(format, output, &lectures)?;
@@ -1,13 +1,25 @@
-use std::{path::Path, sync::Arc};
+use std::{
+    fs::File,
+    io::BufReader,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
 
 use clap::Subcommand;
 use rusaint::{
-    USaintSession, application::course_grades::CourseGradesApplication, client::USaintClientBuilder,
+    USaintSession,
+    application::course_grades::{
+        CourseGradesApplication, export::Transcript, model::GradeSnapshot,
+    },
+    client::USaintClientBuilder,
+    model::LectureCode,
 };
 
 use crate::{
-    output::{OutputFormat, write_output},
-    types::{CourseType, SemesterType},
+    current_semester,
+    output::{OutputFormat, write_output, write_table, write_text},
+    types::{CourseType, SemesterArg},
 };
 
 #[derive(Subcommand)]
@@ -37,10 +49,11 @@ pub enum GradesCommands {
     Classes {
         #[arg(short = 't', long, default_value = "bachelor")]
         course_type: CourseType,
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
         /// 상세 정보 포함 여부
         #[arg(long)]
         include_details: bool,
@@ -49,13 +62,46 @@ pub enum GradesCommands {
     ClassDetail {
         #[arg(short = 't', long, default_value = "bachelor")]
         course_type: CourseType,
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
         /// 과목 코드
         #[arg(short = 'c', long)]
-        code: String,
+        code: LectureCode,
+    },
+    /// 기록부 기준과 증명서 기준 성적 요약 비교
+    Reconcile {
+        #[arg(short = 't', long, default_value = "bachelor")]
+        course_type: CourseType,
+    },
+    /// 전체 성적 요약, 학기별 성적, 과목별 성적을 묶은 성적표
+    Transcript {
+        #[arg(short = 't', long, default_value = "bachelor")]
+        course_type: CourseType,
+        /// 기록부 대신 증명서 기준 성적 요약 사용
+        #[arg(long)]
+        certificated: bool,
+    },
+    /// 이전 스냅샷과 비교하여 새로 게시되었거나 변경된 성적 확인
+    Watch {
+        #[arg(short = 't', long, default_value = "bachelor")]
+        course_type: CourseType,
+        /// 학년도 (생략하면 현재 학년도)
+        #[arg(short = 'y', long)]
+        year: Option<u32>,
+        #[arg(short = 's', long)]
+        semester: SemesterArg,
+        /// 스냅샷 파일 경로
+        #[arg(long, default_value = "grades-snapshot.json")]
+        snapshot_file: PathBuf,
+        /// 반복 확인 간격 (초), 지정하지 않으면 한 번만 확인
+        #[arg(long)]
+        interval: Option<u64>,
+        /// 상세 정보 포함 여부
+        #[arg(long)]
+        include_details: bool,
     },
 }
 
@@ -73,19 +119,24 @@ pub async fn execute(
     match command {
         GradesCommands::RecordedSummary { course_type } => {
             let result = app.recorded_summary(*course_type).await?;
-            write_output(format, output, &result)?;
+            write_table(format, output, "성적 요약", &result, [&result])?;
         }
         GradesCommands::CertificatedSummary { course_type } => {
             let result = app.certificated_summary(*course_type).await?;
-            write_output(format, output, &result)?;
+            write_table(format, output, "성적 요약", &result, [&result])?;
         }
         GradesCommands::ByClassification { course_type } => {
             let result = app.grades_by_classification(*course_type).await?;
-            write_output(format, output, &result)?;
+            match format {
+                OutputFormat::Csv => write_text(output, &result.to_csv())?,
+                OutputFormat::Markdown => write_text(output, &result.to_markdown())?,
+                OutputFormat::Html => write_text(output, &result.to_html())?,
+                _ => write_output(format, output, &result)?,
+            }
         }
         GradesCommands::Semesters { course_type } => {
             let result = app.semesters(*course_type).await?;
-            write_output(format, output, &result)?;
+            write_table(format, output, "학기별 성적", &result, &result)?;
         }
         GradesCommands::Classes {
             course_type,
@@ -93,10 +144,11 @@ pub async fn execute(
             semester,
             include_details,
         } => {
+            let (year, semester) = current_semester::resolve(year, semester).await;
             let result = app
-                .classes(*course_type, year, *semester, include_details)
+                .classes(*course_type, year, semester, include_details)
                 .await?;
-            write_output(format, output, &result)?;
+            write_table(format, output, "과목별 성적", &result, &result)?;
         }
         GradesCommands::ClassDetail {
             course_type,
@@ -104,11 +156,74 @@ pub async fn execute(
             semester,
             code,
         } => {
+            let (year, semester) = current_semester::resolve(year, semester).await;
             let result = app
-                .class_detail(*course_type, year, *semester, &code)
+                .class_detail(*course_type, year, semester, &code)
                 .await?;
+            write_table(format, output, "상세 성적", &result, result.components())?;
+        }
+        GradesCommands::Reconcile { course_type } => {
+            let result = app.reconcile(*course_type).await?;
             write_output(format, output, &result)?;
         }
+        GradesCommands::Transcript {
+            course_type,
+            certificated,
+        } => {
+            let summary = if certificated {
+                app.certificated_summary(*course_type).await?
+            } else {
+                app.recorded_summary(*course_type).await?
+            };
+            let semesters = app.semesters(*course_type).await?;
+            let mut classes = Vec::new();
+            for semester in &semesters {
+                classes.extend(
+                    app.classes(*course_type, semester.year(), semester.semester(), false)
+                        .await?,
+                );
+            }
+            let transcript = Transcript::new(summary, semesters, classes);
+            match format {
+                OutputFormat::Csv => write_text(output, &transcript.to_csv())?,
+                OutputFormat::Markdown => write_text(output, &transcript.to_markdown())?,
+                OutputFormat::Html => write_text(output, &transcript.to_html())?,
+                _ => write_output(format, output, &transcript)?,
+            }
+        }
+        GradesCommands::Watch {
+            course_type,
+            year,
+            semester,
+            snapshot_file,
+            interval,
+            include_details,
+        } => {
+            let (year, semester) = current_semester::resolve(year, semester).await;
+            loop {
+                let previous = if snapshot_file.exists() {
+                    Some(GradeSnapshot::from_json(BufReader::new(File::open(
+                        &snapshot_file,
+                    )?))?)
+                } else {
+                    None
+                };
+                let current = app
+                    .snapshot(*course_type, year, semester, include_details)
+                    .await?;
+                let changes = match &previous {
+                    Some(previous) => current.changes_since(previous),
+                    None => Vec::new(),
+                };
+                write_output(format, output, &changes)?;
+                current.save_to_json(&mut File::create(&snapshot_file)?)?;
+                let Some(interval) = interval else {
+                    break;
+                };
+                tokio::time::sleep(Duration::from_secs(interval)).await;
+                app.reload().await?;
+            }
+        }
     }
 
     Ok(())
@@ -2,18 +2,58 @@ use std::{path::Path, sync::Arc};
 
 use clap::Subcommand;
 use rusaint::{
-    USaintSession, application::graduation_requirements::GraduationRequirementsApplication,
+    USaintSession,
+    application::{
+        course_grades::CourseGradesApplication,
+        course_schedule::{CourseScheduleApplication, model::LectureCategory},
+        graduation_requirements::{GraduationRequirementsApplication, planner::plan_graduation},
+    },
     client::USaintClientBuilder,
 };
 
-use crate::output::{OutputFormat, write_output};
+use crate::{
+    current_semester,
+    output::{OutputFormat, write_output},
+    types::{CourseType, SemesterArg},
+};
 
 #[derive(Subcommand)]
 pub enum GraduationCommands {
     /// 졸업사정 학생정보 조회
     StudentInfo,
     /// 졸업요건 조회
-    Requirements,
+    Requirements {
+        /// 졸업요건에 사용된 과목을 이수구분별 성적과 연결하여 출력
+        #[arg(long)]
+        link_grades: bool,
+        #[arg(short = 't', long, default_value = "bachelor")]
+        course_type: CourseType,
+    },
+    /// 충족되지 않은 졸업요건을 채울 수 있는 개설 강의 제안
+    Plan {
+        #[arg(short = 't', long, default_value = "bachelor")]
+        course_type: CourseType,
+        /// 학년도 (생략하면 현재 학년도)
+        #[arg(short = 'y', long)]
+        year: Option<u32>,
+        #[arg(short = 's', long)]
+        semester: SemesterArg,
+        /// 전공 강의를 검색할 단과대학
+        #[arg(short = 'c', long, requires = "department")]
+        college: Option<String>,
+        /// 전공 강의를 검색할 학부(과)
+        #[arg(short = 'd', long, requires = "college")]
+        department: Option<String>,
+        /// 전공 강의를 검색할 세부 전공
+        #[arg(short = 'm', long)]
+        major: Option<String>,
+        /// 검색할 교양필수 과목명 (여러 번 지정 가능)
+        #[arg(long)]
+        required_elective: Vec<String>,
+        /// 검색할 교양선택 분야 (여러 번 지정 가능)
+        #[arg(long)]
+        optional_elective: Vec<String>,
+    },
 }
 
 pub async fn execute(
@@ -23,7 +63,7 @@ pub async fn execute(
     output: Option<&Path>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut app = USaintClientBuilder::new()
-        .session(session)
+        .session(session.clone())
         .build_into::<GraduationRequirementsApplication>()
         .await?;
 
@@ -32,8 +72,69 @@ pub async fn execute(
             let result = app.student_info().await?;
             write_output(format, output, &result)?;
         }
-        GraduationCommands::Requirements => {
+        GraduationCommands::Requirements {
+            link_grades,
+            course_type,
+        } => {
             let result = app.requirements().await?;
+            if link_grades {
+                let mut grades_app = USaintClientBuilder::new()
+                    .session(session)
+                    .build_into::<CourseGradesApplication>()
+                    .await?;
+                let grades = grades_app.grades_by_classification(*course_type).await?;
+                write_output(format, output, &result.resolve_lectures(&grades))?;
+            } else {
+                write_output(format, output, &result)?;
+            }
+        }
+        GraduationCommands::Plan {
+            course_type,
+            year,
+            semester,
+            college,
+            department,
+            major,
+            required_elective,
+            optional_elective,
+        } => {
+            let (year, semester) = current_semester::resolve(year, semester).await;
+            let mut queries = Vec::new();
+            if let (Some(college), Some(department)) = (&college, &department) {
+                queries.push(LectureCategory::major(
+                    college,
+                    department,
+                    major.as_deref(),
+                ));
+            }
+            queries.extend(
+                required_elective
+                    .iter()
+                    .map(|name| LectureCategory::required_elective(name)),
+            );
+            queries.extend(
+                optional_elective
+                    .iter()
+                    .map(|category| LectureCategory::optional_elective(category)),
+            );
+            let mut schedule_app = USaintClientBuilder::new()
+                .session(session.clone())
+                .build_into::<CourseScheduleApplication>()
+                .await?;
+            let mut grades_app = USaintClientBuilder::new()
+                .session(session)
+                .build_into::<CourseGradesApplication>()
+                .await?;
+            let result = plan_graduation(
+                &mut app,
+                &mut schedule_app,
+                &mut grades_app,
+                *course_type,
+                year,
+                semester,
+                &queries,
+            )
+            .await?;
             write_output(format, output, &result)?;
         }
     }
@@ -1,24 +1,63 @@
 use std::{path::Path, sync::Arc};
 
+use chrono::NaiveDate;
 use clap::Subcommand;
 use rusaint::{
-    USaintSession, application::personal_course_schedule::PersonalCourseScheduleApplication,
+    ApplicationError, RusaintError, USaintSession,
+    application::{
+        chapel::ChapelApplication,
+        course_registration_status::CourseRegistrationStatusApplication,
+        personal_course_schedule::{
+            PersonalCourseScheduleApplication,
+            reconciliation::{linked_schedule, reconcile_schedule},
+        },
+    },
     client::USaintClientBuilder,
 };
 
 use crate::{
-    output::{OutputFormat, write_output},
-    types::SemesterType,
+    current_semester,
+    output::{OutputFormat, write_output, write_text},
+    types::SemesterArg,
 };
 
 #[derive(Subcommand)]
 pub enum PersonalScheduleCommands {
     /// 개인시간표 조회
     Schedule {
+        /// 학년도 (생략하면 현재 학년도)
+        #[arg(short = 'y', long)]
+        year: Option<u32>,
+        #[arg(short = 's', long)]
+        semester: SemesterArg,
+        /// 수강신청 내역과 연결하여 과목번호와 분반 포함
+        #[arg(long)]
+        link_lectures: bool,
+    },
+    /// 개인시간표와 수강신청 내역 비교
+    Reconcile {
+        /// 학년도 (생략하면 현재 학년도)
+        #[arg(short = 'y', long)]
+        year: Option<u32>,
+        #[arg(short = 's', long)]
+        semester: SemesterArg,
+    },
+    /// 개인시간표를 iCalendar(.ics) 형식으로 내보내기
+    ExportIcs {
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
+        /// 학기 시작일 (YYYY-MM-DD)
+        #[arg(long)]
+        start: NaiveDate,
+        /// 학기 종료일 (YYYY-MM-DD)
+        #[arg(long)]
+        end: NaiveDate,
+        /// 채플 일정 포함
+        #[arg(long)]
+        with_chapel: bool,
     },
 }
 
@@ -29,15 +68,64 @@ pub async fn execute(
     output: Option<&Path>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut app = USaintClientBuilder::new()
-        .session(session)
+        .session(session.clone())
         .build_into::<PersonalCourseScheduleApplication>()
         .await?;
 
     match command {
-        PersonalScheduleCommands::Schedule { year, semester } => {
-            let result = app.schedule(year, *semester).await?;
+        PersonalScheduleCommands::Schedule {
+            year,
+            semester,
+            link_lectures,
+        } => {
+            let (year, semester) = current_semester::resolve(year, semester).await;
+            let result = if link_lectures {
+                let mut registration_app = USaintClientBuilder::new()
+                    .session(session)
+                    .build_into::<CourseRegistrationStatusApplication>()
+                    .await?;
+                linked_schedule(&mut app, &mut registration_app, year, semester).await?
+            } else {
+                app.schedule(year, semester).await?
+            };
             write_output(format, output, &result)?;
         }
+        PersonalScheduleCommands::Reconcile { year, semester } => {
+            let (year, semester) = current_semester::resolve(year, semester).await;
+            let mut registration_app = USaintClientBuilder::new()
+                .session(session)
+                .build_into::<CourseRegistrationStatusApplication>()
+                .await?;
+            let result =
+                reconcile_schedule(&mut app, &mut registration_app, year, semester).await?;
+            write_output(format, output, &result)?;
+        }
+        PersonalScheduleCommands::ExportIcs {
+            year,
+            semester,
+            start,
+            end,
+            with_chapel,
+        } => {
+            let (year, semester) = current_semester::resolve(year, semester).await;
+            let schedule = app.schedule(year, semester).await?;
+            let chapel = if with_chapel {
+                let mut chapel_app = USaintClientBuilder::new()
+                    .session(session)
+                    .build_into::<ChapelApplication>()
+                    .await?;
+                match chapel_app.information(year, semester).await {
+                    Ok(information) => Some(information),
+                    Err(RusaintError::ApplicationError(ApplicationError::NoChapelInformation)) => {
+                        None
+                    }
+                    Err(err) => return Err(err.into()),
+                }
+            } else {
+                None
+            };
+            write_text(output, &schedule.to_ics(start, end, chapel.as_ref()))?;
+        }
     }
 
     Ok(())
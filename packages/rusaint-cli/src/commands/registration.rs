@@ -2,23 +2,35 @@ use std::{path::Path, sync::Arc};
 
 use clap::Subcommand;
 use rusaint::{
-    USaintSession, application::course_registration_status::CourseRegistrationStatusApplication,
+    USaintSession,
+    application::{
+        course_registration_status::CourseRegistrationStatusApplication,
+        student_information::StudentInformationApplication,
+    },
     client::USaintClientBuilder,
 };
 
 use crate::{
+    current_semester,
     output::{OutputFormat, write_output},
-    types::SemesterType,
+    types::SemesterArg,
 };
 
 #[derive(Subcommand)]
 pub enum RegistrationCommands {
     /// 수강신청 내역 조회
     Lectures {
+        /// 학년도 (생략하면 현재 학년도)
         #[arg(short = 'y', long)]
-        year: u32,
+        year: Option<u32>,
         #[arg(short = 's', long)]
-        semester: SemesterType,
+        semester: SemesterArg,
+    },
+    /// 입학 이후 전체 학기 수강신청 내역 조회
+    History {
+        /// 입학년도 (기본값: 학적 정보의 입학년도)
+        #[arg(long)]
+        apply_year: Option<u32>,
     },
 }
 
@@ -29,15 +41,29 @@ pub async fn execute(
     output: Option<&Path>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut app = USaintClientBuilder::new()
-        .session(session)
+        .session(session.clone())
         .build_into::<CourseRegistrationStatusApplication>()
         .await?;
 
     match command {
         RegistrationCommands::Lectures { year, semester } => {
-            let lectures: Vec<_> = app.lectures(year, *semester).await?.collect();
+            let (year, semester) = current_semester::resolve(year, semester).await;
+            let lectures: Vec<_> = app.lectures(year, semester).await?.collect();
             write_output(format, output, &lectures)?;
         }
+        RegistrationCommands::History { apply_year } => {
+            let apply_year = match apply_year {
+                Some(apply_year) => apply_year,
+                None => USaintClientBuilder::new()
+                    .session(session)
+                    .build_into::<StudentInformationApplication>()
+                    .await?
+                    .general()?
+                    .apply_year(),
+            };
+            let history = app.history(apply_year).await?;
+            write_output(format, output, &history)?;
+        }
     }
 
     Ok(())
@@ -5,12 +5,18 @@ use rusaint::{
     USaintSession, application::scholarships::ScholarshipsApplication, client::USaintClientBuilder,
 };
 
-use crate::output::{OutputFormat, write_output};
+use crate::output::{OutputFormat, write_table};
 
 #[derive(Subcommand)]
 pub enum ScholarshipsCommands {
     /// 장학금 수혜 내역 조회
     List,
+    /// 학기별 장학금 합계 (--by-name 사용 시 장학금명별 합계)
+    Summary {
+        /// 장학금명별 합계로 표 출력
+        #[arg(long)]
+        by_name: bool,
+    },
 }
 
 pub async fn execute(
@@ -27,7 +33,27 @@ pub async fn execute(
     match command {
         ScholarshipsCommands::List => {
             let result = app.scholarships().await?;
-            write_output(format, output, &result)?;
+            write_table(format, output, "장학금 수혜 내역", &result, &result)?;
+        }
+        ScholarshipsCommands::Summary { by_name } => {
+            let result = app.summary().await?;
+            if by_name {
+                write_table(
+                    format,
+                    output,
+                    "장학금명별 합계",
+                    &result,
+                    result.scholarships(),
+                )?;
+            } else {
+                write_table(
+                    format,
+                    output,
+                    "학기별 장학금 합계",
+                    &result,
+                    result.semesters(),
+                )?;
+            }
         }
     }
 
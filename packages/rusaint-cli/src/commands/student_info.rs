@@ -2,16 +2,45 @@ use std::{path::Path, sync::Arc};
 
 use clap::Subcommand;
 use rusaint::{
-    USaintSession, application::student_information::StudentInformationApplication,
+    USaintSession,
+    application::student_information::{StudentInformationApplication, model::ContactUpdate},
     client::USaintClientBuilder,
 };
 
-use crate::output::{OutputFormat, write_output};
+use crate::output::{OutputFormat, write_bytes, write_output, write_redacted};
 
 #[derive(Subcommand)]
 pub enum StudentInfoCommands {
     /// 일반 학생정보 조회
     General,
+    /// 모든 탭의 학생정보를 한 번에 조회 (실패한 항목은 errors에 기록)
+    Profile,
+    /// 학생 사진 다운로드 (-o 미지정 시 stdout으로 바이너리 출력)
+    Photo,
+    /// 연락처(이메일, 전화번호, 주소) 수정
+    UpdateContact {
+        /// 이메일
+        #[arg(long)]
+        email: Option<String>,
+        /// 집전화
+        #[arg(long)]
+        tel_number: Option<String>,
+        /// 휴대전화
+        #[arg(long)]
+        mobile_number: Option<String>,
+        /// 우편번호
+        #[arg(long)]
+        post_code: Option<String>,
+        /// 주소1
+        #[arg(long)]
+        address: Option<String>,
+        /// 주소2
+        #[arg(long)]
+        specific_address: Option<String>,
+        /// 저장하지 않고 전송할 이벤트만 출력
+        #[arg(long)]
+        dry_run: bool,
+    },
     /// 졸업정보 조회
     Graduation,
     /// 자격증 정보 조회
@@ -37,6 +66,7 @@ pub async fn execute(
     command: StudentInfoCommands,
     format: &OutputFormat,
     output: Option<&Path>,
+    redact: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut app = USaintClientBuilder::new()
         .session(session)
@@ -46,7 +76,35 @@ pub async fn execute(
     match command {
         StudentInfoCommands::General => {
             let result = app.general()?;
-            write_output(format, output, &result)?;
+            write_redacted(format, output, &result, redact)?;
+        }
+        StudentInfoCommands::Profile => {
+            let result = app.profile().await;
+            write_redacted(format, output, &result, redact)?;
+        }
+        StudentInfoCommands::Photo => {
+            let result = app.photo().await?;
+            write_bytes(output, result.data())?;
+        }
+        StudentInfoCommands::UpdateContact {
+            email,
+            tel_number,
+            mobile_number,
+            post_code,
+            address,
+            specific_address,
+            dry_run,
+        } => {
+            let update = ContactUpdate {
+                email,
+                tel_number,
+                mobile_number,
+                post_code,
+                address,
+                specific_address,
+            };
+            let result = app.update_contact(&update, dry_run).await?;
+            write_redacted(format, output, &result, redact)?;
         }
         StudentInfoCommands::Graduation => {
             let result = app.graduation()?;
@@ -58,11 +116,11 @@ pub async fn execute(
         }
         StudentInfoCommands::Work => {
             let result = app.work().await?;
-            write_output(format, output, &result)?;
+            write_redacted(format, output, &result, redact)?;
         }
         StudentInfoCommands::Family => {
             let result = app.family().await?;
-            write_output(format, output, &result)?;
+            write_redacted(format, output, &result, redact)?;
         }
         StudentInfoCommands::Religion => {
             let result = app.religion().await?;
@@ -74,7 +132,7 @@ pub async fn execute(
         }
         StudentInfoCommands::BankAccount => {
             let result = app.bank_account().await?;
-            write_output(format, output, &result)?;
+            write_redacted(format, output, &result, redact)?;
         }
         StudentInfoCommands::AcademicRecord => {
             let result = app.academic_record().await?;
@@ -82,7 +140,7 @@ pub async fn execute(
         }
         StudentInfoCommands::ResearchBankAccount => {
             let result = app.research_bank_account().await?;
-            write_output(format, output, &result)?;
+            write_redacted(format, output, &result, redact)?;
         }
     }
 
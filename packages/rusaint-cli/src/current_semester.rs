@@ -0,0 +1,33 @@
+use rusaint::{
+    application::current_semester::{CurrentSemester, CurrentSemesterSource, current_semester},
+    client::USaintClientBuilder,
+    model::SemesterType,
+};
+
+use crate::types::SemesterArg;
+
+/// 명령줄에서 받은 학년도와 학기를 실제 학년도와 학기로 바꿉니다.
+///
+/// `current`는 로그인 없이 u-saint 강의시간표의 기본 선택값으로 판별하며, 실패하면 날짜로 추정합니다.
+/// 학년도가 주어지지 않았다면 현재 학년도를 사용합니다.
+pub async fn resolve(year: Option<u32>, semester: SemesterArg) -> (u32, SemesterType) {
+    match semester {
+        SemesterArg::Semester(semester) => (
+            year.unwrap_or_else(|| CurrentSemester::today().year()),
+            *semester,
+        ),
+        SemesterArg::Current => {
+            let current = current_semester(USaintClientBuilder::new()).await;
+            let source = match current.source() {
+                CurrentSemesterSource::USaint => "u-saint",
+                CurrentSemesterSource::Date => "날짜로 추정",
+            };
+            eprintln!(
+                "현재 학기: {}년 {} ({source})",
+                current.year(),
+                current.semester()
+            );
+            (year.unwrap_or(current.year()), current.semester())
+        }
+    }
+}
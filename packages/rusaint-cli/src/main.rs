@@ -1,4 +1,5 @@
 mod commands;
+mod current_semester;
 mod output;
 mod session;
 mod types;
@@ -21,7 +22,7 @@ struct Cli {
     #[arg(long, global = true)]
     env_file: Option<PathBuf>,
 
-    /// 출력 포맷 (human 또는 json)
+    /// 출력 포맷 (human, json, csv, markdown, html)
     #[arg(long, global = true, default_value = "human")]
     format: OutputFormat,
 
@@ -29,6 +30,10 @@ struct Cli {
     #[arg(short, long, global = true)]
     output: Option<PathBuf>,
 
+    /// human 출력에서 개인정보(주민번호, 연락처, 계좌번호 등)를 가리지 않고 그대로 출력
+    #[arg(long, global = true)]
+    no_redact: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -61,7 +66,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         Commands::StudentInfo { command } => {
             let session = session::get_session(cli.session_file.as_deref(), false).await?;
-            commands::student_info::execute(session, command, format, output).await?;
+            commands::student_info::execute(session, command, format, output, !cli.no_redact)
+                .await?;
         }
         Commands::Grades { command } => {
             let session = session::get_session(cli.session_file.as_deref(), false).await?;
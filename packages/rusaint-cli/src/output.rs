@@ -5,6 +5,10 @@ use std::{
 };
 
 use clap::ValueEnum;
+use rusaint::{
+    export::{TableRow, html_document, to_csv, to_html_table, to_markdown},
+    redact::{Redact, Redacted},
+};
 use serde::Serialize;
 use serde_json::Value;
 
@@ -12,6 +16,9 @@ use serde_json::Value;
 pub enum OutputFormat {
     Human,
     Json,
+    Csv,
+    Markdown,
+    Html,
 }
 
 pub fn write_output<T: Serialize>(
@@ -27,8 +34,49 @@ pub fn write_output<T: Serialize>(
             format_value(&value, 0, &mut buf);
             buf
         }
+        OutputFormat::Csv | OutputFormat::Markdown | OutputFormat::Html => {
+            let name = format
+                .to_possible_value()
+                .map(|value| value.get_name().to_string())
+                .unwrap_or_default();
+            return Err(format!("이 명령어는 {name} 출력 포맷을 지원하지 않습니다").into());
+        }
     };
 
+    write_text(output, &text)
+}
+
+/// `redact`가 참이면 human 출력에서 민감한 필드를 가립니다.
+pub fn write_redacted<T: Serialize + Redact>(
+    format: &OutputFormat,
+    output: Option<&Path>,
+    data: &T,
+    redact: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if redact && matches!(format, OutputFormat::Human) {
+        write_output(format, output, &Redacted(data))
+    } else {
+        write_output(format, output, data)
+    }
+}
+
+pub fn write_table<'a, S: Serialize, T: TableRow + 'a>(
+    format: &OutputFormat,
+    output: Option<&Path>,
+    title: &str,
+    data: &S,
+    rows: impl IntoIterator<Item = &'a T>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let text = match format {
+        OutputFormat::Human | OutputFormat::Json => return write_output(format, output, data),
+        OutputFormat::Csv => to_csv(rows),
+        OutputFormat::Markdown => to_markdown(rows),
+        OutputFormat::Html => html_document(title, &to_html_table(rows)),
+    };
+    write_text(output, &text)
+}
+
+pub fn write_text(output: Option<&Path>, text: &str) -> Result<(), Box<dyn std::error::Error>> {
     match output {
         Some(path) => {
             let mut file = File::create(path)?;
@@ -46,6 +94,15 @@ pub fn write_output<T: Serialize>(
     Ok(())
 }
 
+pub fn write_bytes(output: Option<&Path>, data: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    match output {
+        Some(path) => File::create(path)?.write_all(data)?,
+        None => io::stdout().lock().write_all(data)?,
+    }
+
+    Ok(())
+}
+
 fn format_value(value: &Value, indent: usize, buf: &mut String) {
     match value {
         Value::Null => buf.push('-'),
@@ -2,4 +2,4 @@ mod course_type;
 mod semester_type;
 
 pub use course_type::CourseType;
-pub use semester_type::SemesterType;
+pub use semester_type::SemesterArg;
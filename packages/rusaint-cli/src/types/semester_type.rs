@@ -73,3 +73,31 @@ impl ValueEnum for SemesterType {
         }
     }
 }
+
+/// `--semester`로 받는 학기. `current`는 명령을 실행할 때 현재 학기로 바뀝니다.
+#[derive(Debug, Clone, Copy)]
+pub enum SemesterArg {
+    Semester(SemesterType),
+    Current,
+}
+
+impl ValueEnum for SemesterArg {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            SemesterArg::Semester(SemesterType(rusaint::model::SemesterType::One)),
+            SemesterArg::Semester(SemesterType(rusaint::model::SemesterType::Summer)),
+            SemesterArg::Semester(SemesterType(rusaint::model::SemesterType::Two)),
+            SemesterArg::Semester(SemesterType(rusaint::model::SemesterType::Winter)),
+            SemesterArg::Current,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        match self {
+            SemesterArg::Semester(semester) => semester.to_possible_value(),
+            SemesterArg::Current => {
+                Some(clap::builder::PossibleValue::new("current").help("현재 학기"))
+            }
+        }
+    }
+}
@@ -25,5 +25,8 @@ pub mod lecture_assessment;
 /// 장학금 수혜내역 조회: [`ScholarshipsApplication`](scholarships::ScholarshipsApplication)
 pub mod scholarships;
 
+/// 현재 학년도와 학기 판별: [`current_semester`](current_semester::current_semester)
+pub mod current_semester;
+
 /// 플랫폼 지원을 위한 데이터
 pub mod model;
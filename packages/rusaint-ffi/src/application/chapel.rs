@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use rusaint::{application::chapel::model::ChapelInformation, model::SemesterType};
+use rusaint::{
+    application::chapel::model::{ChapelForecast, ChapelHistory, ChapelInformation, ChapelPolicy},
+    model::SemesterType,
+};
 use tokio::sync::RwLock;
 
 use crate::application::model::YearSemester;
@@ -21,6 +24,26 @@ impl ChapelApplication {
         Ok(self.0.write().await.information(year, semester).await?)
     }
 
+    /// 해당 학기의 채플 출결을 분석하고 오늘 날짜를 기준으로 통과 여부를 예측합니다.
+    pub async fn forecast(
+        &self,
+        year: u32,
+        semester: SemesterType,
+        policy: ChapelPolicy,
+    ) -> Result<ChapelForecast, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .forecast(year, semester, &policy)
+            .await?)
+    }
+
+    /// 입학년도부터 현재 학기까지 모든 정규학기의 채플 정보를 가져옵니다.
+    pub async fn history(&self, apply_year: u32) -> Result<ChapelHistory, RusaintError> {
+        Ok(self.0.write().await.history(apply_year).await?)
+    }
+
     /// 최신 정보를 조회합니다. 새로고침 시 유용합니다.
     pub async fn lookup(&self) -> Result<(), RusaintError> {
         Ok(self.0.write().await.lookup().await?)
@@ -33,6 +56,7 @@ impl ChapelApplication {
 
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
+    /// 현재 학기는 [`current_semester`](crate::application::current_semester::current_semester)로 확인하세요.
     pub async fn get_selected_semester(&self) -> Result<YearSemester, RusaintError> {
         let (year, semester) = self.0.read().await.get_selected_semester()?;
         Ok(YearSemester::new(year, semester))
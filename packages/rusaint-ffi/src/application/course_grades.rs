@@ -1,10 +1,11 @@
-use std::{collections::HashMap, sync::Arc};
+use std::sync::Arc;
 
 use rusaint::{
     application::course_grades::model::{
-        ClassGrade, CourseType, GradeSummary, GradesByClassification, SemesterGrade,
+        ClassGrade, ClassGradeDetail, CourseType, GradeChange, GradeReconciliation, GradeSnapshot,
+        GradeSummary, GradesByClassification, SemesterGrade,
     },
-    model::SemesterType,
+    model::{LectureCode, SemesterType},
 };
 use tokio::sync::RwLock;
 
@@ -73,13 +74,13 @@ impl CourseGradesApplication {
         course_type: CourseType,
         year: u32,
         semester: SemesterType,
-        code: &str,
-    ) -> Result<HashMap<String, f32>, RusaintError> {
+        code: LectureCode,
+    ) -> Result<ClassGradeDetail, RusaintError> {
         Ok(self
             .0
             .write()
             .await
-            .class_detail(course_type, year, semester, code)
+            .class_detail(course_type, year, semester, &code)
             .await?)
     }
 
@@ -96,6 +97,31 @@ impl CourseGradesApplication {
             .await?)
     }
 
+    /// 학적부 평점과 증명 평점, 학기별 성적을 함께 가져와 항목별 차이와 추정 원인, 일관되지 않은 값을 보고합니다.
+    pub async fn reconcile(
+        &self,
+        course_type: CourseType,
+    ) -> Result<GradeReconciliation, RusaintError> {
+        Ok(self.0.write().await.reconcile(course_type).await?)
+    }
+
+    /// 주어진 학기의 학기별 성적과 수업별 성적을 스냅샷으로 가져옵니다.
+    /// 이전에 저장한 스냅샷과 [`grade_changes`]로 비교하여 새로 게시되었거나 변경된 성적을 찾을 수 있습니다.
+    pub async fn snapshot(
+        &self,
+        course_type: CourseType,
+        year: u32,
+        semester: SemesterType,
+        include_details: bool,
+    ) -> Result<GradeSnapshot, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .snapshot(course_type, year, semester, include_details)
+            .await?)
+    }
+
     /// 최신 정보를 조회합니다. 새로고침 시 유용합니다.
     pub async fn lookup(&self) -> Result<(), RusaintError> {
         Ok(self.0.write().await.lookup().await?)
@@ -108,12 +134,31 @@ impl CourseGradesApplication {
 
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
+    /// 현재 학기는 [`current_semester`](crate::application::current_semester::current_semester)로 확인하세요.
     pub async fn get_selected_semester(&self) -> Result<YearSemester, RusaintError> {
         let (year, semester) = self.0.read().await.get_selected_semester()?;
         Ok(YearSemester::new(year, semester))
     }
 }
 
+/// 이전 성적 스냅샷과 비교하여 새로 게시되었거나 변경된 성적을 반환합니다.
+#[uniffi::export]
+pub fn grade_changes(previous: GradeSnapshot, current: GradeSnapshot) -> Vec<GradeChange> {
+    current.changes_since(&previous)
+}
+
+/// 성적 스냅샷을 json 형식으로 변환합니다.
+#[uniffi::export]
+pub fn grade_snapshot_to_json(snapshot: GradeSnapshot) -> Result<String, RusaintError> {
+    Ok(snapshot.to_json()?)
+}
+
+/// json 형식으로 저장된 성적 스냅샷을 읽습니다.
+#[uniffi::export]
+pub fn grade_snapshot_from_json(json: &str) -> Result<GradeSnapshot, RusaintError> {
+    Ok(GradeSnapshot::from_json(json.as_bytes())?)
+}
+
 /// [`CourseGradesApplication`] 생성을 위한 빌더
 #[derive(uniffi::Object)]
 pub struct CourseGradesApplicationBuilder {}
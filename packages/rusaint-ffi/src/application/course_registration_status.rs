@@ -1,7 +1,8 @@
 use std::sync::Arc;
 
 use rusaint::{
-    application::course_registration_status::model::RegisteredLecture, model::SemesterType,
+    application::course_registration_status::model::{RegisteredLecture, RegistrationHistory},
+    model::SemesterType,
 };
 use tokio::sync::RwLock;
 
@@ -18,6 +19,7 @@ pub struct CourseRegistrationStatusApplication(
 impl CourseRegistrationStatusApplication {
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
+    /// 현재 학기는 [`current_semester`](crate::application::current_semester::current_semester)로 확인하세요.
     pub async fn get_selected_semester(&self) -> Result<YearSemester, RusaintError> {
         let (year, semester) = self.0.read().await.get_selected_semester()?;
         Ok(YearSemester::new(year, semester))
@@ -38,6 +40,11 @@ impl CourseRegistrationStatusApplication {
             .collect())
     }
 
+    /// 입학년도부터 (한국 표준시 기준) 현재 학기까지 모든 학기(계절학기 포함)의 수강신청 내역을 가져옵니다.
+    pub async fn history(&self, apply_year: u32) -> Result<RegistrationHistory, RusaintError> {
+        Ok(self.0.write().await.history(apply_year).await?)
+    }
+
     /// 페이지를 새로고침합니다.
     pub async fn reload(&self) -> Result<(), RusaintError> {
         Ok(self.0.write().await.reload().await?)
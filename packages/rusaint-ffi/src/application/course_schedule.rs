@@ -4,7 +4,7 @@ use rusaint::{
     application::course_schedule::model::{
         DetailedLecture, Lecture, LectureCategory, LectureDetail, LectureSyllabus,
     },
-    model::SemesterType,
+    model::{LectureCode, SemesterType},
 };
 use tokio::sync::RwLock;
 
@@ -37,6 +37,7 @@ impl CourseScheduleApplication {
 
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
+    /// 현재 학기는 [`current_semester`](crate::application::current_semester::current_semester)로 확인하세요.
     pub async fn get_selected_semester(&self) -> Result<YearSemester, RusaintError> {
         let (year, semester) = self.0.read().await.get_selected_semester()?;
         Ok(YearSemester::new(year, semester))
@@ -184,15 +185,18 @@ impl CourseScheduleApplication {
 
     /// 주어진 과목번호에 해당하는 강의의 상세 정보를 가져옵니다.
     /// `find_lectures` 함수를 먼저 호출하여 강의를 검색한 이후에 사용되어야 합니다.
-    pub async fn lecture_detail(&self, code: &str) -> Result<LectureDetail, RusaintError> {
-        Ok(self.0.write().await.lecture_detail(code).await?)
+    pub async fn lecture_detail(&self, code: LectureCode) -> Result<LectureDetail, RusaintError> {
+        Ok(self.0.write().await.lecture_detail(&code).await?)
     }
 
     /// 주어진 과목번호에 해당하는 강의의 강의계획서(syllabus) 데이터를 OZ 서버에서 가져옵니다.
     /// `find_lectures` 함수를 먼저 호출하여 강의를 검색한 이후에 사용되어야 합니다.
     /// 강의계획서가 없는 강의의 경우 에러를 반환합니다.
-    pub async fn lecture_syllabus(&self, code: &str) -> Result<LectureSyllabus, RusaintError> {
-        Ok(self.0.write().await.lecture_syllabus(code).await?)
+    pub async fn lecture_syllabus(
+        &self,
+        code: LectureCode,
+    ) -> Result<LectureSyllabus, RusaintError> {
+        Ok(self.0.write().await.lecture_syllabus(&code).await?)
     }
 
     /// 검색된 모든 강의의 상세 정보와 강의계획서를 함께 조회합니다.
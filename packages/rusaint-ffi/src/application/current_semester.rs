@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use crate::session::USaintSession;
+use rusaint::application::current_semester::CurrentSemester;
+
+/// 현재 학년도와 학기를 판별합니다.
+///
+/// 새로 불러온 강의시간표 애플리케이션의 기본 선택 학기를 사용하며, 실패하면 한국 표준시 기준 오늘 날짜로 추정합니다.
+/// 세션이 주어지지 않으면 로그인 없이 조회합니다.
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn current_semester(session: Option<Arc<USaintSession>>) -> CurrentSemester {
+    let builder = match session {
+        Some(session) => crate::client_builder().session(session.original()),
+        None => crate::client_builder(),
+    };
+    rusaint::application::current_semester::current_semester(builder).await
+}
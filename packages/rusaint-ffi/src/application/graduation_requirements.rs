@@ -1,8 +1,11 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use crate::{error::RusaintError, session::USaintSession};
-use rusaint::application::graduation_requirements::model::{
-    GraduationRequirements, GraduationStudent,
+use rusaint::application::{
+    course_grades::model::GradesByClassification,
+    graduation_requirements::model::{
+        GraduationRequirements, GraduationStudent, RequirementLecture,
+    },
 };
 use tokio::sync::RwLock;
 
@@ -30,6 +33,15 @@ impl GraduationRequirementsApplication {
     }
 }
 
+/// 모든 졸업 요건에 사용된 과목을 이수구분별 성적과 연결합니다. 졸업요건 이름을 키로 사용합니다.
+#[uniffi::export]
+pub fn resolve_requirement_lectures(
+    requirements: GraduationRequirements,
+    grades: GradesByClassification,
+) -> HashMap<String, Vec<RequirementLecture>> {
+    requirements.resolve_lectures(&grades)
+}
+
 /// [`GraduationRequirementsApplication`] 생성을 위한 빌더
 #[derive(uniffi::Object)]
 pub struct GraduationRequirementsApplicationBuilder {}
@@ -2,8 +2,10 @@ use std::sync::Arc;
 
 use crate::application::model::YearSemester;
 use crate::{error::RusaintError, session::USaintSession};
-use rusaint::application::lecture_assessment::model::LectureAssessmentResult;
-use rusaint::model::SemesterType;
+use rusaint::application::lecture_assessment::model::{
+    AssessmentAggregation, LectureAssessmentDetail, LectureAssessmentResult,
+};
+use rusaint::model::{LectureCode, SemesterType};
 use tokio::sync::RwLock;
 
 /// [강의평가조회](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMB2W1010)
@@ -21,10 +23,11 @@ impl LectureAssessmentApplication {
         year: u32,
         semester: SemesterType,
         lecture_name: Option<String>,
-        lecture_code: Option<u32>,
+        lecture_code: Option<LectureCode>,
         professor_name: Option<String>,
     ) -> Result<Vec<LectureAssessmentResult>, RusaintError> {
         let lecture_name = lecture_name.as_deref();
+        let lecture_code = lecture_code.as_ref();
         let professor_name = professor_name.as_deref();
         Ok(self
             .0
@@ -34,8 +37,66 @@ impl LectureAssessmentApplication {
             .await?)
     }
 
+    /// 주어진 강의평가 결과의 상세 화면을 열어 문항별 결과를 가져옵니다.
+    /// `find_assessments`를 먼저 호출하여 해당 결과가 포함된 학기를 검색한 이후에 사용되어야 합니다.
+    pub async fn assessment_detail(
+        &self,
+        result: LectureAssessmentResult,
+    ) -> Result<LectureAssessmentDetail, RusaintError> {
+        Ok(self.0.write().await.assessment_detail(&result).await?)
+    }
+
+    /// 검색 조건에 맞는 강의평가 정보를 문항별 결과와 함께 가져옵니다.
+    #[uniffi::method(default(lecture_name = None, lecture_code = None, professor_name = None))]
+    pub async fn find_assessment_details(
+        &self,
+        year: u32,
+        semester: SemesterType,
+        lecture_name: Option<String>,
+        lecture_code: Option<LectureCode>,
+        professor_name: Option<String>,
+    ) -> Result<Vec<LectureAssessmentDetail>, RusaintError> {
+        let lecture_name = lecture_name.as_deref();
+        let lecture_code = lecture_code.as_ref();
+        let professor_name = professor_name.as_deref();
+        Ok(self
+            .0
+            .write()
+            .await
+            .find_assessment_details(year, semester, lecture_name, lecture_code, professor_name)
+            .await?)
+    }
+
+    /// `from_year`부터 `to_year`까지 모든 학기의 강의평가를 검색하여 교수별, 과목별로 집계합니다.
+    #[uniffi::method(default(lecture_name = None, lecture_code = None, professor_name = None))]
+    pub async fn aggregate_assessments(
+        &self,
+        from_year: u32,
+        to_year: u32,
+        lecture_name: Option<String>,
+        lecture_code: Option<LectureCode>,
+        professor_name: Option<String>,
+    ) -> Result<AssessmentAggregation, RusaintError> {
+        let lecture_name = lecture_name.as_deref();
+        let lecture_code = lecture_code.as_ref();
+        let professor_name = professor_name.as_deref();
+        Ok(self
+            .0
+            .write()
+            .await
+            .aggregate_assessments(
+                from_year,
+                to_year,
+                lecture_name,
+                lecture_code,
+                professor_name,
+            )
+            .await?)
+    }
+
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
+    /// 현재 학기는 [`current_semester`](crate::application::current_semester::current_semester)로 확인하세요.
     pub async fn get_selected_semester(&self) -> Result<YearSemester, RusaintError> {
         let (year, semester) = self.0.read().await.get_selected_semester()?;
         Ok(YearSemester::new(year, semester))
@@ -2,7 +2,13 @@ use std::sync::Arc;
 
 use crate::application::model::YearSemester;
 use crate::{error::RusaintError, session::USaintSession};
-use rusaint::application::personal_course_schedule::model::PersonalCourseSchedule;
+use chrono::NaiveDate;
+use rusaint::application::chapel::model::ChapelInformation;
+use rusaint::application::course_registration_status::model::RegisteredLecture;
+use rusaint::application::personal_course_schedule::model::{
+    CourseScheduleInformation, PersonalCourseSchedule, Weekday,
+};
+use rusaint::application::personal_course_schedule::reconciliation::ScheduleReconciliation;
 use rusaint::model::SemesterType;
 use tokio::sync::RwLock;
 
@@ -25,6 +31,7 @@ impl PersonalCourseScheduleApplication {
 
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
+    /// 현재 학기는 [`current_semester`](crate::application::current_semester::current_semester)로 확인하세요.
     pub async fn get_selected_semester(&self) -> Result<YearSemester, RusaintError> {
         let (year, semester) = self.0.read().await.get_selected_semester()?;
         Ok(YearSemester::new(year, semester))
@@ -36,6 +43,71 @@ impl PersonalCourseScheduleApplication {
     }
 }
 
+/// 수강신청 내역의 강의시간(강의실)으로 요일별 시간표를 만듭니다.
+#[uniffi::export]
+pub fn schedule_from_registered_lectures(
+    lectures: Vec<RegisteredLecture>,
+) -> PersonalCourseSchedule {
+    PersonalCourseSchedule::from_registered_lectures(&lectures)
+}
+
+/// 수강신청 내역과 과목명, 수업 시간을 비교하여 시간표의 각 수업에 과목번호와 분반을 연결합니다.
+#[uniffi::export]
+pub fn link_schedule_lectures(
+    schedule: PersonalCourseSchedule,
+    lectures: Vec<RegisteredLecture>,
+) -> PersonalCourseSchedule {
+    let mut schedule = schedule;
+    schedule.link_registered_lectures(&lectures);
+    schedule
+}
+
+/// 해당 요일의 주어진 시각에 진행 중인 수업을 반환합니다.
+#[uniffi::export]
+pub fn schedule_class_at(
+    schedule: PersonalCourseSchedule,
+    weekday: Weekday,
+    hour: u32,
+    minute: u32,
+) -> Option<CourseScheduleInformation> {
+    let time = chrono::NaiveTime::from_hms_opt(hour, minute, 0)?;
+    schedule.class_at(weekday, time).cloned()
+}
+
+/// 해당 요일의 주어진 시각 이후에 시작하는 가장 빠른 수업을 반환합니다.
+#[uniffi::export]
+pub fn schedule_next_class(
+    schedule: PersonalCourseSchedule,
+    weekday: Weekday,
+    hour: u32,
+    minute: u32,
+) -> Option<CourseScheduleInformation> {
+    let time = chrono::NaiveTime::from_hms_opt(hour, minute, 0)?;
+    schedule.next_class(weekday, time).cloned()
+}
+
+/// 시간표를 학기 시작일부터 종료일까지 매주 반복되는 iCalendar 문서로 변환합니다. 채플 정보가 주어지면 채플 일정도 포함합니다.
+#[uniffi::export]
+pub fn personal_schedule_to_ics(
+    schedule: PersonalCourseSchedule,
+    start: NaiveDate,
+    end: NaiveDate,
+    chapel: Option<ChapelInformation>,
+) -> String {
+    schedule.to_ics(start, end, chapel.as_ref())
+}
+
+/// 개인수업시간표와 수강신청 내역을 과목명 기준으로 비교합니다.
+#[uniffi::export]
+pub fn reconcile_personal_schedule(
+    year: u32,
+    semester: SemesterType,
+    schedule: PersonalCourseSchedule,
+    lectures: Vec<RegisteredLecture>,
+) -> ScheduleReconciliation {
+    ScheduleReconciliation::new(year, semester, &schedule, &lectures)
+}
+
 /// [`PersonalCourseScheduleApplication`] 생성을 위한 빌더
 #[derive(uniffi::Object)]
 pub struct PersonalCourseScheduleApplicationBuilder {}
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use crate::{error::RusaintError, session::USaintSession};
-use rusaint::application::scholarships::model::Scholarship;
+use rusaint::application::scholarships::model::{Scholarship, ScholarshipSummary};
 use tokio::sync::RwLock;
 
 /// [장학금수혜내역조회](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW7530n)
@@ -17,6 +17,11 @@ impl ScholarshipsApplication {
         Ok(self.0.write().await.scholarships().await?)
     }
 
+    /// 장학금 수혜 내역을 학기별, 장학금명별로 합산한 요약을 가져옵니다.
+    pub async fn summary(&self) -> Result<ScholarshipSummary, RusaintError> {
+        Ok(self.0.write().await.summary().await?)
+    }
+
     /// 페이지를 새로고침합니다.
     pub async fn reload(&self) -> Result<(), RusaintError> {
         Ok(self.0.write().await.reload().await?)
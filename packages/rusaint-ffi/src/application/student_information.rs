@@ -2,9 +2,9 @@ use std::sync::Arc;
 
 use crate::{error::RusaintError, session::USaintSession};
 use rusaint::application::student_information::model::{
-    StudentAcademicRecords, StudentBankAccount, StudentFamily, StudentGraduation,
-    StudentInformation, StudentQualification, StudentReligion, StudentResearchBankAccount,
-    StudentTransferRecords, StudentWorkInformation,
+    ContactUpdate, ContactUpdateResult, StudentAcademicRecords, StudentBankAccount, StudentFamily,
+    StudentGraduation, StudentInformation, StudentPhoto, StudentProfile, StudentQualification,
+    StudentReligion, StudentResearchBankAccount, StudentTransferRecords, StudentWorkInformation,
 };
 use tokio::sync::RwLock;
 
@@ -21,6 +21,32 @@ impl StudentInformationApplication {
         Ok(self.0.read().await.general()?)
     }
 
+    /// 모든 탭의 학생 정보를 모아 프로필로 반환합니다. 가져오지 못한 항목은 `errors`에 기록됩니다.
+    pub async fn profile(&self) -> StudentProfile {
+        self.0.write().await.profile().await
+    }
+
+    /// 학생 사진을 가져옵니다.
+    pub async fn photo(&self) -> Result<StudentPhoto, RusaintError> {
+        Ok(self.0.read().await.photo().await?)
+    }
+
+    /// 학생의 연락처(이메일, 전화번호, 주소)를 수정하고 저장합니다.
+    /// `dry_run`이 참이면 서버에 이벤트를 전송하지 않고 전송할 이벤트 목록만 반환합니다.
+    #[uniffi::method(default(dry_run = false))]
+    pub async fn update_contact(
+        &self,
+        update: ContactUpdate,
+        dry_run: bool,
+    ) -> Result<ContactUpdateResult, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .update_contact(&update, dry_run)
+            .await?)
+    }
+
     /// 학생의 졸업과 관련된 정보를 반환합니다.
     pub async fn graduation(&self) -> Result<StudentGraduation, RusaintError> {
         Ok(self.0.read().await.graduation()?)
@@ -1,5 +1,7 @@
 uniffi::setup_scaffolding!();
 
+uniffi::use_remote_type!(rusaint::chrono::NaiveDate);
+
 /// rusaint에서 제공하는 기본 u-saint 애플리케이션
 pub mod application;
 
@@ -35,4 +35,7 @@ pub mod lecture_assessment;
 
 /// 장학금 수혜내역 조회: [`ScholarshipsApplication`](scholarships::ScholarshipsApplication)
 pub mod scholarships;
+
+/// 현재 학년도와 학기 판별: [`current_semester`](current_semester::current_semester)
+pub mod current_semester;
 pub(crate) mod utils;
@@ -1,8 +1,13 @@
-use model::{ChapelAbsenceRequest, ChapelAttendance, ChapelInformation, GeneralChapelInformation};
+use model::{
+    ChapelAbsenceRequest, ChapelAttendance, ChapelForecast, ChapelHistory, ChapelInformation,
+    ChapelPolicy, ChapelSemesterRecord, GeneralChapelInformation,
+};
 
-use crate::application::utils::semester::get_selected_semester;
+use crate::application::utils::semester::{
+    get_selected_semester, latest_regular_semester, regular_semesters_between, today_kst,
+};
 use crate::client::{USaintApplication, USaintClient};
-use crate::{RusaintError, model::SemesterType};
+use crate::{ApplicationError, RusaintError, model::SemesterType};
 
 use wdpe::command::WebDynproCommandExecutor;
 use wdpe::element::parser::ElementParser;
@@ -130,8 +135,38 @@ impl<'a> ChapelApplication {
         ))
     }
 
+    /// 해당 학기의 채플 출결을 분석하고 한국 표준시 기준 오늘 날짜로 통과 여부를 예측합니다.
+    pub async fn forecast(
+        &mut self,
+        year: u32,
+        semester: SemesterType,
+        policy: &ChapelPolicy,
+    ) -> Result<ChapelForecast, RusaintError> {
+        let information = self.information(year, semester).await?;
+        Ok(information.forecast(today_kst(), policy))
+    }
+
+    /// 입학년도부터 (한국 표준시 기준) 현재 학기까지 모든 정규학기의 채플 정보를 가져옵니다.
+    ///
+    /// 입학년도는 [`StudentInformation::apply_year`](crate::application::student_information::model::StudentInformation::apply_year)로 확인할 수 있습니다.
+    /// 채플 정보가 없는 학기는 오류 대신 채플 정보가 비어 있는 기록으로 반환됩니다.
+    pub async fn history(&mut self, apply_year: u32) -> Result<ChapelHistory, RusaintError> {
+        let until = latest_regular_semester(today_kst());
+        let mut records = Vec::new();
+        for (year, semester) in regular_semesters_between(apply_year, until) {
+            let information = match self.information(year, semester).await {
+                Ok(information) => Some(information),
+                Err(RusaintError::ApplicationError(ApplicationError::NoChapelInformation)) => None,
+                Err(err) => return Err(err),
+            };
+            records.push(ChapelSemesterRecord::new(year, semester, information));
+        }
+        Ok(ChapelHistory::new(records))
+    }
+
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
+    /// 현재 학기는 [`current_semester`](crate::application::current_semester::current_semester)로 확인하세요.
     pub fn get_selected_semester(&self) -> Result<(u32, SemesterType), RusaintError> {
         Ok(get_selected_semester(
             &self.client,
@@ -5,7 +5,11 @@ use serde::{
     de::{IntoDeserializer, value::MapDeserializer},
 };
 
-use crate::application::utils::de_with::{deserialize_semester_type, deserialize_u32_string};
+use chrono::NaiveDate;
+
+use crate::application::utils::de_with::{
+    deserialize_optional_date, deserialize_semester_type, deserialize_u32_string,
+};
 use crate::{RusaintError, error::ApplicationError, model::SemesterType};
 use wdpe::command::WebDynproCommandExecutor;
 use wdpe::element::parser::ElementParser;
@@ -26,6 +30,14 @@ use wdpe::{
     error::{ElementError, WebDynproError},
 };
 
+mod forecast;
+mod history;
+mod status;
+
+pub use forecast::{ChapelForecast, ChapelOutcome, ChapelPolicy};
+pub use history::{ChapelHistory, ChapelSemesterRecord};
+pub use status::{AbsenceRequestStatus, ChapelAttendanceStatus, ChapelResult};
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 /// 학기별 채플 정보
@@ -78,6 +90,11 @@ impl ChapelInformation {
     pub fn absence_requests(&self) -> &[ChapelAbsenceRequest] {
         &self.absence_requests
     }
+
+    /// 주어진 날짜를 기준으로 채플 출결을 분석하고 통과 여부를 예측합니다.
+    pub fn forecast(&self, today: NaiveDate, policy: &ChapelPolicy) -> ChapelForecast {
+        ChapelForecast::new(self, today, policy)
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -106,7 +123,7 @@ pub struct GeneralChapelInformation {
     )]
     absence_time: u32,
     #[serde(rename(deserialize = "성적"))]
-    result: String,
+    result: ChapelResult,
     #[serde(rename(deserialize = "비고"))]
     note: String,
 }
@@ -162,7 +179,7 @@ impl<'a> GeneralChapelInformation {
     }
 
     /// 성적을 반환합니다.
-    pub fn result(&self) -> &str {
+    pub fn result(&self) -> &ChapelResult {
         &self.result
     }
 
@@ -198,8 +215,11 @@ pub struct ChapelAttendance {
         deserialize_with = "deserialize_u32_string"
     )]
     division: u32,
-    #[serde(rename(deserialize = "수업일자"))]
-    class_date: String,
+    #[serde(
+        rename(deserialize = "수업일자"),
+        deserialize_with = "deserialize_optional_date"
+    )]
+    class_date: Option<NaiveDate>,
     #[serde(rename(deserialize = "강의구분"))]
     category: String,
     #[serde(rename(deserialize = "강사"))]
@@ -209,7 +229,7 @@ pub struct ChapelAttendance {
     #[serde(rename(deserialize = "제목"))]
     title: String,
     #[serde(rename(deserialize = "출결상태"))]
-    attendance: String,
+    attendance: ChapelAttendanceStatus,
     #[serde(rename(deserialize = "평가"))]
     result: String,
     #[serde(rename(deserialize = "비고"))]
@@ -241,9 +261,9 @@ impl<'a> ChapelAttendance {
         self.division
     }
 
-    /// 수업일자를 반환합니다.
-    pub fn class_date(&self) -> &str {
-        &self.class_date
+    /// 수업일자를 반환합니다. 수업일자가 비어 있다면 `None`을 반환합니다.
+    pub fn class_date(&self) -> Option<NaiveDate> {
+        self.class_date
     }
 
     /// 강의구분을 반환합니다.
@@ -267,7 +287,7 @@ impl<'a> ChapelAttendance {
     }
 
     /// 출결상태를 반환합니다.
-    pub fn attendance(&self) -> &str {
+    pub fn attendance(&self) -> &ChapelAttendanceStatus {
         &self.attendance
     }
 
@@ -315,22 +335,34 @@ pub struct ChapelAbsenceRequest {
     semester: SemesterType,
     #[serde(rename(deserialize = "결석구분상세"))]
     absence_detail: String,
-    #[serde(rename(deserialize = "결석시작일자"))]
-    absence_start: String,
-    #[serde(rename(deserialize = "결석종료일자"))]
-    absence_end: String,
+    #[serde(
+        rename(deserialize = "결석시작일자"),
+        deserialize_with = "deserialize_optional_date"
+    )]
+    absence_start: Option<NaiveDate>,
+    #[serde(
+        rename(deserialize = "결석종료일자"),
+        deserialize_with = "deserialize_optional_date"
+    )]
+    absence_end: Option<NaiveDate>,
     #[serde(rename(deserialize = "결석사유(국문)"))]
     absence_reason_kr: String,
     #[serde(rename(deserialize = "결석사유(영문)"))]
     absence_reason_en: String,
-    #[serde(rename(deserialize = "신청일자"))]
-    application_date: String,
-    #[serde(rename(deserialize = "승인일자"))]
-    approval_date: String,
+    #[serde(
+        rename(deserialize = "신청일자"),
+        deserialize_with = "deserialize_optional_date"
+    )]
+    application_date: Option<NaiveDate>,
+    #[serde(
+        rename(deserialize = "승인일자"),
+        deserialize_with = "deserialize_optional_date"
+    )]
+    approval_date: Option<NaiveDate>,
     #[serde(rename(deserialize = "거부사유"))]
     denial_reason: String,
     #[serde(rename(deserialize = "상태"))]
-    status: String,
+    status: AbsenceRequestStatus,
 }
 
 impl<'a> ChapelAbsenceRequest {
@@ -369,13 +401,13 @@ impl<'a> ChapelAbsenceRequest {
     }
 
     /// 결석시작일자를 반환합니다.
-    pub fn absence_start(&self) -> &str {
-        &self.absence_start
+    pub fn absence_start(&self) -> Option<NaiveDate> {
+        self.absence_start
     }
 
     /// 결석종료일자를 반환합니다.
-    pub fn absence_end(&self) -> &str {
-        &self.absence_end
+    pub fn absence_end(&self) -> Option<NaiveDate> {
+        self.absence_end
     }
 
     /// 국문 결석사유를 반환합니다.
@@ -389,13 +421,13 @@ impl<'a> ChapelAbsenceRequest {
     }
 
     /// 신청일자를 반환합니다.
-    pub fn application_date(&self) -> &str {
-        &self.application_date
+    pub fn application_date(&self) -> Option<NaiveDate> {
+        self.application_date
     }
 
     /// 승인일자를 반환합니다.
-    pub fn approval_date(&self) -> &str {
-        &self.approval_date
+    pub fn approval_date(&self) -> Option<NaiveDate> {
+        self.approval_date
     }
 
     /// 거부사유를 반환합니다.
@@ -404,9 +436,22 @@ impl<'a> ChapelAbsenceRequest {
     }
 
     /// 요청 상태를 반환합니다.
-    pub fn status(&self) -> &str {
+    pub fn status(&self) -> &AbsenceRequestStatus {
         &self.status
     }
+
+    /// 결석신청 기간에 해당 수업의 수업일자가 포함되는지 여부를 반환합니다.
+    ///
+    /// 결석시작일자가 없다면 포함되지 않는 것으로, 결석종료일자가 없다면 시작일 하루만 신청한 것으로 봅니다.
+    pub fn covers(&self, attendance: &ChapelAttendance) -> bool {
+        let Some(start) = self.absence_start else {
+            return false;
+        };
+        let end = self.absence_end.unwrap_or(start);
+        attendance
+            .class_date
+            .is_some_and(|date| (start..=end).contains(&date))
+    }
 }
 
 impl<'body> FromSapTable<'body> for ChapelAbsenceRequest {
@@ -0,0 +1,397 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::{ChapelAbsenceRequest, ChapelAttendanceStatus, ChapelInformation, ChapelResult};
+
+/// 채플 통과 여부를 예측하는 데 사용하는 기준
+///
+/// 기본값은 전체 수업 횟수의 1/3까지 결석을 허용하고, 지각 2회를 결석 1회로 환산합니다.
+/// 학기나 학과에 따라 기준이 다를 수 있으므로 필요하다면 직접 지정하여야 합니다.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ChapelPolicy {
+    /// 허용되는 최대 결석 횟수 (`None`이면 전체 수업 횟수의 1/3)
+    max_absences: Option<u32>,
+    /// 결석 1회로 환산되는 지각 횟수 (`None`이면 지각을 결석으로 환산하지 않음)
+    lates_per_absence: Option<u32>,
+    /// 학기 전체 수업 횟수 (`None`이면 출결 정보에 포함된 수업 수)
+    total_sessions: Option<u32>,
+}
+
+impl Default for ChapelPolicy {
+    fn default() -> Self {
+        Self {
+            max_absences: None,
+            lates_per_absence: Some(2),
+            total_sessions: None,
+        }
+    }
+}
+
+impl ChapelPolicy {
+    /// 새로운 채플 통과 기준을 만듭니다.
+    pub fn new(
+        max_absences: Option<u32>,
+        lates_per_absence: Option<u32>,
+        total_sessions: Option<u32>,
+    ) -> Self {
+        Self {
+            max_absences,
+            lates_per_absence,
+            total_sessions,
+        }
+    }
+
+    /// 허용되는 최대 결석 횟수
+    pub fn max_absences(&self) -> Option<u32> {
+        self.max_absences
+    }
+
+    /// 결석 1회로 환산되는 지각 횟수
+    pub fn lates_per_absence(&self) -> Option<u32> {
+        self.lates_per_absence
+    }
+
+    /// 학기 전체 수업 횟수
+    pub fn total_sessions(&self) -> Option<u32> {
+        self.total_sessions
+    }
+}
+
+/// 예상되는 채플 결과
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ChapelOutcome {
+    /// 남은 수업에 모두 결석하더라도 통과
+    Passed,
+    /// 남은 결석 허용 횟수 안에서 출석하면 통과
+    OnTrack,
+    /// 결석 허용 횟수를 초과하여 통과할 수 없음
+    Failed,
+}
+
+impl ChapelOutcome {
+    fn project(absences: u32, max_absences: u32, remaining_sessions: u32) -> ChapelOutcome {
+        if absences > max_absences {
+            ChapelOutcome::Failed
+        } else if absences + remaining_sessions <= max_absences {
+            ChapelOutcome::Passed
+        } else {
+            ChapelOutcome::OnTrack
+        }
+    }
+}
+
+/// 채플 출결 분석 및 통과 여부 예측
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ChapelForecast {
+    /// 학기 전체 수업 횟수
+    total_sessions: u32,
+    /// 출결이 처리된 수업 횟수
+    held_sessions: u32,
+    /// 남은 수업 횟수
+    remaining_sessions: u32,
+    /// 출석 횟수
+    present: u32,
+    /// 지각 횟수
+    late: u32,
+    /// 결석 횟수
+    absent: u32,
+    /// 공결 횟수
+    excused: u32,
+    /// 지각 환산을 포함한 결석 횟수
+    absences: u32,
+    /// 허용되는 최대 결석 횟수
+    max_absences: u32,
+    /// 앞으로 더 결석할 수 있는 횟수
+    remaining_allowed_absences: u32,
+    /// 예상 결과
+    outcome: ChapelOutcome,
+    /// 처리되지 않은 결석신청 수
+    pending_requests: u32,
+    /// 처리되지 않은 결석신청이 모두 승인되었을 때의 결석 횟수
+    absences_if_approved: u32,
+    /// 처리되지 않은 결석신청이 모두 승인되었을 때의 예상 결과
+    outcome_if_approved: ChapelOutcome,
+}
+
+impl ChapelForecast {
+    /// 채플 정보와 기준 날짜로 통과 여부를 예측합니다.
+    ///
+    /// 출결이 처리되지 않았거나 `today` 이후인 수업을 남은 수업으로 봅니다.
+    /// 결석 횟수는 u-saint에 표시된 결석일수와 출결 정보로 계산한 결석 횟수 중 큰 값을 사용하며,
+    /// u-saint에 성적이 확정되어 있다면 결석신청 승인 여부와 관계없이 확정된 성적을 따릅니다.
+    pub fn new(
+        information: &ChapelInformation,
+        today: NaiveDate,
+        policy: &ChapelPolicy,
+    ) -> ChapelForecast {
+        let attendances = information.attendances();
+        let (mut present, mut late, mut absent, mut excused, mut remaining_sessions) =
+            (0, 0, 0, 0, 0);
+        for attendance in attendances {
+            if !attendance.attendance().is_recorded()
+                || attendance.class_date().is_some_and(|date| date > today)
+            {
+                remaining_sessions += 1;
+                continue;
+            }
+            match attendance.attendance() {
+                ChapelAttendanceStatus::Present => present += 1,
+                ChapelAttendanceStatus::Late => late += 1,
+                ChapelAttendanceStatus::Absent => absent += 1,
+                ChapelAttendanceStatus::Excused => excused += 1,
+                _ => {}
+            }
+        }
+        let listed_sessions = attendances.len() as u32;
+        let total_sessions = policy.total_sessions.unwrap_or(listed_sessions);
+        remaining_sessions += total_sessions.saturating_sub(listed_sessions);
+        let held_sessions = total_sessions.saturating_sub(remaining_sessions);
+
+        let late_absences = policy
+            .lates_per_absence
+            .filter(|count| *count > 0)
+            .map_or(0, |count| late / count);
+        let absences =
+            (absent + late_absences).max(information.general_information().absence_time());
+        let max_absences = policy.max_absences.unwrap_or(total_sessions / 3);
+        let remaining_allowed_absences = max_absences.saturating_sub(absences);
+
+        let pending: Vec<&ChapelAbsenceRequest> = information
+            .absence_requests()
+            .iter()
+            .filter(|request| request.status().is_pending())
+            .collect();
+        let covered = attendances
+            .iter()
+            .filter(|attendance| *attendance.attendance() == ChapelAttendanceStatus::Absent)
+            .filter(|attendance| pending.iter().any(|request| request.covers(attendance)))
+            .count() as u32;
+        let absences_if_approved = absences.saturating_sub(covered);
+
+        let (outcome, outcome_if_approved) = match information.general_information().result() {
+            ChapelResult::Pass => (ChapelOutcome::Passed, ChapelOutcome::Passed),
+            ChapelResult::Fail => (ChapelOutcome::Failed, ChapelOutcome::Failed),
+            _ => {
+                let outcome = ChapelOutcome::project(absences, max_absences, remaining_sessions);
+                let outcome_if_approved = if covered > 0 {
+                    ChapelOutcome::project(absences_if_approved, max_absences, remaining_sessions)
+                } else {
+                    outcome
+                };
+                (outcome, outcome_if_approved)
+            }
+        };
+        ChapelForecast {
+            total_sessions,
+            held_sessions,
+            remaining_sessions,
+            present,
+            late,
+            absent,
+            excused,
+            absences,
+            max_absences,
+            remaining_allowed_absences,
+            outcome,
+            pending_requests: pending.len() as u32,
+            absences_if_approved,
+            outcome_if_approved,
+        }
+    }
+
+    /// 학기 전체 수업 횟수
+    pub fn total_sessions(&self) -> u32 {
+        self.total_sessions
+    }
+
+    /// 출결이 처리된 수업 횟수
+    pub fn held_sessions(&self) -> u32 {
+        self.held_sessions
+    }
+
+    /// 남은 수업 횟수
+    pub fn remaining_sessions(&self) -> u32 {
+        self.remaining_sessions
+    }
+
+    /// 출석 횟수
+    pub fn present(&self) -> u32 {
+        self.present
+    }
+
+    /// 지각 횟수
+    pub fn late(&self) -> u32 {
+        self.late
+    }
+
+    /// 결석 횟수
+    pub fn absent(&self) -> u32 {
+        self.absent
+    }
+
+    /// 공결 횟수
+    pub fn excused(&self) -> u32 {
+        self.excused
+    }
+
+    /// 지각 환산을 포함한 결석 횟수
+    pub fn absences(&self) -> u32 {
+        self.absences
+    }
+
+    /// 허용되는 최대 결석 횟수
+    pub fn max_absences(&self) -> u32 {
+        self.max_absences
+    }
+
+    /// 앞으로 더 결석할 수 있는 횟수
+    pub fn remaining_allowed_absences(&self) -> u32 {
+        self.remaining_allowed_absences
+    }
+
+    /// 예상 결과
+    pub fn outcome(&self) -> ChapelOutcome {
+        self.outcome
+    }
+
+    /// 처리되지 않은 결석신청 수
+    pub fn pending_requests(&self) -> u32 {
+        self.pending_requests
+    }
+
+    /// 처리되지 않은 결석신청이 모두 승인되었을 때의 결석 횟수
+    pub fn absences_if_approved(&self) -> u32 {
+        self.absences_if_approved
+    }
+
+    /// 처리되지 않은 결석신청이 모두 승인되었을 때의 예상 결과
+    pub fn outcome_if_approved(&self) -> ChapelOutcome {
+        self.outcome_if_approved
+    }
+
+    /// 처리되지 않은 결석신청의 승인 여부가 예상 결과를 바꾸는지 여부
+    pub fn approval_changes_outcome(&self) -> bool {
+        self.outcome != self.outcome_if_approved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::chapel::model::{ChapelAttendance, GeneralChapelInformation};
+    use crate::model::SemesterType;
+
+    fn attendance(date: &str, status: &str) -> ChapelAttendance {
+        serde_json::from_value(serde_json::json!({
+            "분반": "1", "수업일자": date, "강의구분": "", "강사": "", "소속": "",
+            "제목": "", "출결상태": status, "평가": "", "비고": "",
+        }))
+        .unwrap()
+    }
+
+    fn information(
+        absence_time: &str,
+        result: &str,
+        attendances: Vec<ChapelAttendance>,
+        absence_requests: Vec<ChapelAbsenceRequest>,
+    ) -> ChapelInformation {
+        let general: GeneralChapelInformation = serde_json::from_value(serde_json::json!({
+            "분반": "1", "시간표": "", "강의실": "", "층수": "1", "좌석번호": "",
+            "결석일수": absence_time, "성적": result, "비고": "",
+        }))
+        .unwrap();
+        ChapelInformation::new(
+            2025,
+            SemesterType::One,
+            general,
+            attendances,
+            absence_requests,
+        )
+    }
+
+    fn request(start: &str, end: &str) -> ChapelAbsenceRequest {
+        serde_json::from_value(serde_json::json!({
+            "학년도": "2025", "학기": "1 학기", "결석구분상세": "질병",
+            "결석시작일자": start, "결석종료일자": end,
+            "결석사유(국문)": "", "결석사유(영문)": "", "신청일자": "",
+            "승인일자": "", "거부사유": "", "상태": "신청",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn forecasts_remaining_absences() {
+        let attendances = vec![
+            attendance("2025.03.04", "출석"),
+            attendance("2025.03.11", "결석"),
+            attendance("2025.03.18", "지각"),
+            attendance("2025.03.25", "지각"),
+            attendance("2025.04.01", ""),
+            attendance("2025.04.08", ""),
+        ];
+        let info = information("1", "", attendances, vec![]);
+        let today = NaiveDate::from_ymd_opt(2025, 3, 26).unwrap();
+        let forecast =
+            ChapelForecast::new(&info, today, &ChapelPolicy::new(Some(3), Some(2), None));
+        assert_eq!(forecast.held_sessions(), 4);
+        assert_eq!(forecast.remaining_sessions(), 2);
+        assert_eq!(forecast.absences(), 2);
+        assert_eq!(forecast.remaining_allowed_absences(), 1);
+        assert_eq!(forecast.outcome(), ChapelOutcome::OnTrack);
+        assert!(!forecast.approval_changes_outcome());
+    }
+
+    #[test]
+    fn pending_request_changes_outcome() {
+        let attendances = vec![
+            attendance("2025.03.04", "결석"),
+            attendance("2025.03.11", "결석"),
+            attendance("2025.03.18", "출석"),
+        ];
+        let info = information(
+            "2",
+            "",
+            attendances,
+            vec![request("2025.03.10", "2025.03.12")],
+        );
+        let today = NaiveDate::from_ymd_opt(2025, 3, 20).unwrap();
+        let forecast = ChapelForecast::new(&info, today, &ChapelPolicy::new(Some(1), None, None));
+        assert_eq!(forecast.outcome(), ChapelOutcome::Failed);
+        assert_eq!(forecast.pending_requests(), 1);
+        assert_eq!(forecast.absences_if_approved(), 1);
+        assert_eq!(forecast.outcome_if_approved(), ChapelOutcome::Passed);
+        assert!(forecast.approval_changes_outcome());
+    }
+
+    #[test]
+    fn finalized_result_is_not_changed_by_requests() {
+        let attendances = vec![
+            attendance("2025.03.04", "결석"),
+            attendance("2025.03.11", "결석"),
+        ];
+        let info = information("2", "F", attendances, vec![request("2025.03.04", "")]);
+        let today = NaiveDate::from_ymd_opt(2025, 6, 20).unwrap();
+        let forecast = ChapelForecast::new(&info, today, &ChapelPolicy::new(Some(1), None, None));
+        assert_eq!(forecast.absences_if_approved(), 1);
+        assert_eq!(forecast.outcome(), ChapelOutcome::Failed);
+        assert_eq!(forecast.outcome_if_approved(), ChapelOutcome::Failed);
+        assert!(!forecast.approval_changes_outcome());
+        assert!(!request("", "").covers(&info.attendances()[0]));
+    }
+
+    #[test]
+    fn counts_attendance_without_class_date() {
+        let attendances = vec![attendance("", "결석"), attendance("", "")];
+        let info = information("1", "", attendances, vec![request("2025.03.04", "")]);
+        assert_eq!(info.attendances()[0].class_date(), None);
+        assert!(!info.absence_requests()[0].covers(&info.attendances()[0]));
+        let today = NaiveDate::from_ymd_opt(2025, 3, 26).unwrap();
+        let forecast = ChapelForecast::new(&info, today, &ChapelPolicy::new(Some(3), None, None));
+        assert_eq!(forecast.held_sessions(), 1);
+        assert_eq!(forecast.remaining_sessions(), 1);
+        assert_eq!(forecast.absences(), 1);
+    }
+}
@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ChapelInformation, ChapelResult};
+use crate::model::SemesterType;
+
+/// 한 학기의 채플 기록
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ChapelSemesterRecord {
+    /// 학년도
+    year: u32,
+    /// 학기
+    semester: SemesterType,
+    /// 채플 정보 (채플을 수강하지 않은 학기라면 `None`)
+    information: Option<ChapelInformation>,
+}
+
+impl ChapelSemesterRecord {
+    pub(crate) fn new(
+        year: u32,
+        semester: SemesterType,
+        information: Option<ChapelInformation>,
+    ) -> Self {
+        Self {
+            year,
+            semester,
+            information,
+        }
+    }
+
+    /// 학년도
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 학기
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 채플 정보 (채플을 수강하지 않은 학기라면 `None`)
+    pub fn information(&self) -> Option<&ChapelInformation> {
+        self.information.as_ref()
+    }
+
+    /// 채플 성적 (채플을 수강하지 않은 학기라면 `None`)
+    pub fn result(&self) -> Option<&ChapelResult> {
+        self.information
+            .as_ref()
+            .map(|information| information.general_information().result())
+    }
+
+    /// 채플을 통과한 학기인지 여부
+    pub fn is_passed(&self) -> bool {
+        self.result() == Some(&ChapelResult::Pass)
+    }
+}
+
+/// 입학 이후 전체 학기의 채플 기록
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ChapelHistory {
+    /// 학기별 채플 기록
+    records: Vec<ChapelSemesterRecord>,
+    /// 채플을 통과한 학기 수
+    passed_semesters: u32,
+}
+
+impl ChapelHistory {
+    pub(crate) fn new(records: Vec<ChapelSemesterRecord>) -> Self {
+        let passed_semesters = records.iter().filter(|record| record.is_passed()).count() as u32;
+        Self {
+            records,
+            passed_semesters,
+        }
+    }
+
+    /// 학기별 채플 기록
+    pub fn records(&self) -> &[ChapelSemesterRecord] {
+        &self.records
+    }
+
+    /// 채플을 수강한 학기의 기록
+    pub fn attended(&self) -> impl Iterator<Item = &ChapelSemesterRecord> {
+        self.records
+            .iter()
+            .filter(|record| record.information.is_some())
+    }
+
+    /// 채플을 통과한 학기 수
+    pub fn passed_semesters(&self) -> u32 {
+        self.passed_semesters
+    }
+}
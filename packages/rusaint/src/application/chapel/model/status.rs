@@ -0,0 +1,195 @@
+use std::{convert::Infallible, fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// 채플 수업의 출결상태
+///
+/// 문자열로 직렬화되며, 알 수 없는 상태는 [`ChapelAttendanceStatus::Unknown`]으로 보존됩니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", from = "String")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ChapelAttendanceStatus {
+    /// 출석
+    Present,
+    /// 지각
+    Late,
+    /// 결석
+    Absent,
+    /// 공결 등 결석으로 처리되지 않는 인정 결석
+    Excused,
+    /// 아직 출결이 처리되지 않음
+    Pending,
+    /// 알 수 없는 상태
+    Unknown(String),
+}
+
+impl ChapelAttendanceStatus {
+    /// 출결이 처리된 수업인지 여부
+    pub fn is_recorded(&self) -> bool {
+        !matches!(self, Self::Pending)
+    }
+}
+
+impl FromStr for ChapelAttendanceStatus {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "출석" => Self::Present,
+            "지각" => Self::Late,
+            "결석" => Self::Absent,
+            "공결" | "인정결석" | "출석인정" => Self::Excused,
+            "" | "미처리" | "미입력" => Self::Pending,
+            other => Self::Unknown(other.to_string()),
+        })
+    }
+}
+
+impl Display for ChapelAttendanceStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Self::Present => "출석",
+            Self::Late => "지각",
+            Self::Absent => "결석",
+            Self::Excused => "공결",
+            Self::Pending => "",
+            Self::Unknown(status) => status,
+        };
+        write!(f, "{str}")
+    }
+}
+
+impl From<String> for ChapelAttendanceStatus {
+    fn from(value: String) -> Self {
+        let Ok(status) = value.parse();
+        status
+    }
+}
+
+impl From<ChapelAttendanceStatus> for String {
+    fn from(value: ChapelAttendanceStatus) -> Self {
+        value.to_string()
+    }
+}
+
+/// 채플 성적
+///
+/// 문자열로 직렬화되며, 알 수 없는 성적은 [`ChapelResult::Unknown`]으로 보존됩니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", from = "String")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ChapelResult {
+    /// 통과
+    Pass,
+    /// 미통과
+    Fail,
+    /// 아직 성적이 확정되지 않음
+    Pending,
+    /// 알 수 없는 성적
+    Unknown(String),
+}
+
+impl FromStr for ChapelResult {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "P" | "Pass" | "PASS" | "합격" | "통과" => Self::Pass,
+            "F" | "Fail" | "FAIL" | "NP" | "불합격" | "미통과" => Self::Fail,
+            "" | "미확정" => Self::Pending,
+            other => Self::Unknown(other.to_string()),
+        })
+    }
+}
+
+impl Display for ChapelResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Self::Pass => "P",
+            Self::Fail => "F",
+            Self::Pending => "",
+            Self::Unknown(result) => result,
+        };
+        write!(f, "{str}")
+    }
+}
+
+impl From<String> for ChapelResult {
+    fn from(value: String) -> Self {
+        let Ok(result) = value.parse();
+        result
+    }
+}
+
+impl From<ChapelResult> for String {
+    fn from(value: ChapelResult) -> Self {
+        value.to_string()
+    }
+}
+
+/// 채플 결석신청의 처리 상태
+///
+/// 문자열로 직렬화되며, 알 수 없는 상태는 [`AbsenceRequestStatus::Unknown`]으로 보존됩니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", from = "String")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum AbsenceRequestStatus {
+    /// 신청 후 처리 대기 중
+    Requested,
+    /// 승인
+    Approved,
+    /// 거부
+    Denied,
+    /// 알 수 없는 상태
+    Unknown(String),
+}
+
+impl AbsenceRequestStatus {
+    /// 아직 처리되지 않은 신청인지 여부
+    pub fn is_pending(&self) -> bool {
+        matches!(self, Self::Requested)
+    }
+
+    /// 승인된 신청인지 여부
+    pub fn is_approved(&self) -> bool {
+        matches!(self, Self::Approved)
+    }
+}
+
+impl FromStr for AbsenceRequestStatus {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "신청" | "접수" | "대기" | "승인대기" => Self::Requested,
+            "승인" | "완료" => Self::Approved,
+            "거부" | "반려" | "불가" => Self::Denied,
+            other => Self::Unknown(other.to_string()),
+        })
+    }
+}
+
+impl Display for AbsenceRequestStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Self::Requested => "신청",
+            Self::Approved => "승인",
+            Self::Denied => "거부",
+            Self::Unknown(status) => status,
+        };
+        write!(f, "{str}")
+    }
+}
+
+impl From<String> for AbsenceRequestStatus {
+    fn from(value: String) -> Self {
+        let Ok(status) = value.parse();
+        status
+    }
+}
+
+impl From<AbsenceRequestStatus> for String {
+    fn from(value: AbsenceRequestStatus) -> Self {
+        value.to_string()
+    }
+}
@@ -1,4 +1,7 @@
-use self::model::{ClassGrade, CourseType, GradeSummary, GradesByClassification, SemesterGrade};
+use self::model::{
+    ClassGrade, ClassGradeDetail, ClassScore, CourseType, GradeReconciliation, GradeSnapshot,
+    GradeSummary, GradesByClassification, LetterGrade, SemesterGrade,
+};
 use crate::application::utils::input_field::InputFieldExt as _;
 use crate::application::utils::oz::{
     extract_oz_url_from_script_calls, fetch_data_module, parse_oz_url_params,
@@ -7,7 +10,10 @@ use crate::application::utils::popup::close_popups;
 use crate::application::utils::sap_table::try_table_into_with_scroll;
 use crate::application::utils::semester::get_selected_semester;
 use crate::client::{USaintApplication, USaintClient};
-use crate::{ApplicationError, RusaintError, model::SemesterType};
+use crate::{
+    ApplicationError, RusaintError,
+    model::{LectureCode, SemesterType},
+};
 use std::collections::HashMap;
 use wdpe::body::Body;
 use wdpe::command::WebDynproCommandExecutor;
@@ -173,6 +179,7 @@ impl<'a> CourseGradesApplication {
 
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
+    /// 현재 학기는 [`current_semester`](crate::application::current_semester::current_semester)로 확인하세요.
     pub fn get_selected_semester(&self) -> Result<(u32, SemesterType), RusaintError> {
         Ok(get_selected_semester(
             &self.client,
@@ -377,10 +384,10 @@ impl<'a> CourseGradesApplication {
     async fn class_detail_in_popup(
         &mut self,
         press_event: Event,
-    ) -> Result<HashMap<String, f32>, RusaintError> {
+    ) -> Result<ClassGradeDetail, RusaintError> {
         self.client.process_event(false, press_event).await?;
 
-        let parse_table_in_popup = |body: &Body| -> Result<HashMap<String, f32>, WebDynproError> {
+        let parse_table_in_popup = |body: &Body| -> Result<ClassGradeDetail, WebDynproError> {
             let table_inside_popup_selector = Selector::parse(r#"[ct="PW"] [ct="ST"]"#).unwrap();
             let parser = ElementParser::new(body);
             let mut table_inside_popup = parser.document().select(&table_inside_popup_selector);
@@ -402,19 +409,20 @@ impl<'a> CourseGradesApplication {
                 .map(|(key, val)| {
                     let str = val.trim();
                     if str.is_empty() {
-                        return Ok((key, -1.0));
+                        return Ok((key, None));
                     }
                     let float = str.parse::<f32>().or(Err(ElementError::InvalidContent {
                         element: format!("TABLE: {}, key: {}", table_elem.id(), key),
                         content: "(not an correct f32)".to_string(),
                     }))?;
-                    Ok((key, float))
+                    Ok((key, Some(float)))
                 })
-                .collect::<Result<HashMap<String, f32>, WebDynproError>>()
+                .collect::<Result<Vec<(String, Option<f32>)>, WebDynproError>>()
+                .map(ClassGradeDetail::from_labeled)
         };
-        let table = parse_table_in_popup(self.client.body())?;
+        let detail = parse_table_in_popup(self.client.body())?;
         self.close_popups().await?;
-        Ok(HashMap::from_iter(table))
+        Ok(detail)
     }
 
     /// 주어진 학기의 수업별 성적을 가져옵니다. `include_details`가 `true`인 경우 수업의 상세 성적도 가져옵니다.
@@ -492,7 +500,7 @@ impl<'a> CourseGradesApplication {
         };
         let mut ret: Vec<ClassGrade> = vec![];
         for (btn_event, values) in class_grades {
-            let detail: Option<HashMap<String, f32>> = if let Some(btn_event) = btn_event {
+            let detail: Option<ClassGradeDetail> = if let Some(btn_event) = btn_event {
                 if include_details {
                     Some(self.class_detail_in_popup(btn_event).await?)
                 } else {
@@ -502,14 +510,16 @@ impl<'a> CourseGradesApplication {
                 None
             };
             let parsed: Option<ClassGrade> = (|| {
+                let Ok(score) = values["성적"].parse::<ClassScore>();
+                let Ok(rank) = values["등급"].parse::<LetterGrade>();
                 Some(ClassGrade::new(
                     year,
                     semester,
-                    values["과목코드"].trim().to_owned(),
+                    LectureCode::new(&values["과목코드"]),
                     values["과목명"].trim().to_owned(),
                     values["과목학점"].parse().ok()?,
-                    values["성적"].parse().ok()?,
-                    values["등급"].trim().to_owned(),
+                    score,
+                    rank,
                     values["교수명"].trim().to_owned(),
                     detail,
                 ))
@@ -521,7 +531,7 @@ impl<'a> CourseGradesApplication {
         Ok(ret)
     }
 
-    /// 주어진 수업의 상세 성적 정보를 가져옵니다. 만약 상세 성적이 음수라면, 성적이 비어 있다는 의미입니다.
+    /// 주어진 수업의 상세 성적 정보를 가져옵니다. 비어 있는 항목의 점수는 `None`입니다.
     /// ### 예시
     /// ```no_run
     /// # tokio_test::block_on(async {
@@ -536,7 +546,7 @@ impl<'a> CourseGradesApplication {
     /// let class = classes.iter().next().unwrap();
     /// let class_detail = app.class_detail(CourseType::Bachelor, 2022, SemesterType::Two, class.code()).await.unwrap();
     /// println!("{:?}", class_detail);
-    /// // {"출석(20.000)": 20.0, "중간고사(30.000)": 30.0, "과제(20.000)": 20.0, "기말고사(30.000)": 28.0}
+    /// // ClassGradeDetail { midterm: Some(GradeComponent { label: "중간고사(30.000)", name: "중간고사", weight: Some(30.0), score: Some(30.0) }), ... }
     /// # })
     /// ```
     pub async fn class_detail(
@@ -544,8 +554,8 @@ impl<'a> CourseGradesApplication {
         course_type: CourseType,
         year: u32,
         semester: SemesterType,
-        code: &str,
-    ) -> Result<HashMap<String, f32>, RusaintError> {
+        code: &LectureCode,
+    ) -> Result<ClassGradeDetail, RusaintError> {
         let year = year.to_string();
         {
             self.close_popups().await?;
@@ -564,7 +574,7 @@ impl<'a> CourseGradesApplication {
                             if let Some(ElementDefWrapper::TextView(code_elem)) = cell.content() {
                                 parser
                                     .element_from_def(&code_elem)
-                                    .is_ok_and(|elem| elem.text() == code)
+                                    .is_ok_and(|elem| elem.text().trim() == code.as_str())
                             } else {
                                 false
                             }
@@ -593,11 +603,80 @@ impl<'a> CourseGradesApplication {
         self.class_detail_in_popup(btn).await
     }
 
+    /// 주어진 학기의 학기별 성적과 수업별 성적을 스냅샷으로 가져옵니다.
+    /// 이전에 저장한 스냅샷과 [`GradeSnapshot::changes_since()`]로 비교하여 새로 게시되었거나 변경된 성적을 찾을 수 있습니다.
+    ///
+    /// `include_details`가 `true`인 경우 상세 성적도 함께 가져오므로 상세 성적의 변경도 감지할 수 있지만, [`classes()`](Self::classes)와 같이 요청 수가 늘어납니다.
+    /// ### 예시
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// # use std::sync::Arc;
+    /// # use rusaint::USaintSession;
+    /// # use rusaint::application::course_grades::{ model::CourseType, CourseGradesApplication };
+    /// # use rusaint::client::USaintClientBuilder;
+    /// # use rusaint::model::SemesterType;
+    /// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+    /// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseGradesApplication>().await.unwrap();
+    /// let previous = app.snapshot(CourseType::Bachelor, 2024, SemesterType::Two, false).await.unwrap();
+    /// // ...
+    /// let current = app.snapshot(CourseType::Bachelor, 2024, SemesterType::Two, false).await.unwrap();
+    /// let changes = current.changes_since(&previous);
+    /// println!("{:?}", changes);
+    /// // [GradePosted { ... }]
+    /// # })
+    /// ```
+    pub async fn snapshot(
+        &mut self,
+        course_type: CourseType,
+        year: u32,
+        semester: SemesterType,
+        include_details: bool,
+    ) -> Result<GradeSnapshot, RusaintError> {
+        let semester_grade = self
+            .semesters(course_type)
+            .await?
+            .into_iter()
+            .find(|grade| grade.year() == year && grade.semester() == semester);
+        let classes = self
+            .classes(course_type, year, semester, include_details)
+            .await?;
+        Ok(GradeSnapshot::new(year, semester, semester_grade, classes))
+    }
+
+    /// 학적부 평점과 증명 평점, 학기별 성적을 함께 가져와 항목별 차이와 추정 원인, 일관되지 않은 값을 보고합니다.
+    /// ### 예시
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// # use std::sync::Arc;
+    /// # use rusaint::USaintSession;
+    /// # use rusaint::application::course_grades::{ model::CourseType, CourseGradesApplication };
+    /// # use rusaint::client::USaintClientBuilder;
+    /// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+    /// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseGradesApplication>().await.unwrap();
+    /// let reconciliation = app.reconcile(CourseType::Bachelor).await.unwrap();
+    /// for difference in reconciliation.differences() {
+    ///     println!("{:?}", difference);
+    /// }
+    /// // SummaryDifference { field: AttemptedCredits, recorded: 130.0, certificated: 127.0, difference: -3.0, category: Retake }
+    /// # })
+    /// ```
+    pub async fn reconcile(
+        &mut self,
+        course_type: CourseType,
+    ) -> Result<GradeReconciliation, RusaintError> {
+        let recorded = self.recorded_summary(course_type).await?;
+        let certificated = self.certificated_summary(course_type).await?;
+        let semesters = self.semesters(course_type).await?;
+        Ok(GradeReconciliation::new(recorded, certificated, &semesters))
+    }
+
     fn body(&self) -> &Body {
         self.client.body()
     }
 }
 
+/// [`CourseGradesApplication`]의 성적 데이터를 CSV, Markdown, HTML로 내보내기 위한 도구
+pub mod export;
 /// [`CourseGradesApplication`]에서 사용하는 데이터
 pub mod model;
 
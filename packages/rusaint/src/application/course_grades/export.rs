@@ -0,0 +1,275 @@
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+
+use super::model::{
+    ClassGrade, ClassGradeItem, GradeComponent, GradeSummary, GradesByClassification, SemesterGrade,
+};
+use crate::export::{TableRow, escape_html, html_document, to_csv, to_html_table, to_markdown};
+
+fn optional(value: Option<f32>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+fn rank(rank: (u32, u32)) -> String {
+    format!("{}/{}", rank.0, rank.1)
+}
+
+impl TableRow for GradeSummary {
+    fn headers() -> Vec<&'static str> {
+        vec![
+            "신청학점",
+            "취득학점",
+            "평점계",
+            "평점평균",
+            "산술평균",
+            "P/F 학점계",
+        ]
+    }
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.attempted_credits().to_string(),
+            self.earned_credits().to_string(),
+            self.grade_points_sum().to_string(),
+            self.grade_points_average().to_string(),
+            self.arithmetic_mean().to_string(),
+            self.pf_earned_credits().to_string(),
+        ]
+    }
+}
+
+impl TableRow for SemesterGrade {
+    fn headers() -> Vec<&'static str> {
+        vec![
+            "학년도",
+            "학기",
+            "신청학점",
+            "취득학점",
+            "P/F학점",
+            "평점평균",
+            "평점계",
+            "산술평균",
+            "학기별석차",
+            "전체석차",
+        ]
+    }
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.year().to_string(),
+            self.semester().to_string(),
+            self.attempted_credits().to_string(),
+            self.earned_credits().to_string(),
+            self.pf_earned_credits().to_string(),
+            self.grade_points_average().to_string(),
+            self.grade_points_sum().to_string(),
+            self.arithmetic_mean().to_string(),
+            rank(self.semester_rank()),
+            rank(self.general_rank()),
+        ]
+    }
+}
+
+impl TableRow for ClassGrade {
+    fn headers() -> Vec<&'static str> {
+        vec![
+            "이수학년도",
+            "이수학기",
+            "과목코드",
+            "과목명",
+            "과목학점",
+            "성적",
+            "등급",
+            "교수명",
+        ]
+    }
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.year().to_string(),
+            self.semester().to_string(),
+            self.code().to_string(),
+            self.class_name().to_string(),
+            self.grade_points().to_string(),
+            self.score().to_string(),
+            self.rank().to_string(),
+            self.professor().to_string(),
+        ]
+    }
+}
+
+impl TableRow for ClassGradeItem {
+    fn headers() -> Vec<&'static str> {
+        vec![
+            "이수구분",
+            "학년도",
+            "학기",
+            "과목코드",
+            "과목명",
+            "학점",
+            "성적",
+            "등급",
+            "비고",
+        ]
+    }
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.classification().to_string(),
+            self.year().to_string(),
+            self.semester().to_string(),
+            self.course_code().to_string(),
+            self.course_name().to_string(),
+            optional(self.credits()),
+            self.score().to_string(),
+            self.grade().to_string(),
+            self.note().to_string(),
+        ]
+    }
+}
+
+impl TableRow for GradeComponent {
+    fn headers() -> Vec<&'static str> {
+        vec!["항목", "반영비율", "점수"]
+    }
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.name().to_string(),
+            optional(self.weight()),
+            optional(self.score()),
+        ]
+    }
+}
+
+impl GradesByClassification {
+    fn student_line(&self) -> String {
+        format!(
+            "{} {} / {} {} {} / {}학년 / 조회일 {}",
+            self.student_number(),
+            self.student_name(),
+            self.college(),
+            self.department(),
+            self.major(),
+            self.year_level(),
+            self.audit_date()
+        )
+    }
+
+    /// 이수구분별 성적을 CSV 형식으로 변환합니다.
+    pub fn to_csv(&self) -> String {
+        to_csv(self.grades())
+    }
+
+    /// 이수구분별 성적을 Markdown 형식으로 변환합니다.
+    pub fn to_markdown(&self) -> String {
+        format!(
+            "# 이수구분별 성적\n\n{}\n\n{}",
+            self.student_line(),
+            to_markdown(self.grades())
+        )
+    }
+
+    /// 이수구분별 성적을 인쇄 가능한 단독 HTML 문서로 변환합니다.
+    pub fn to_html(&self) -> String {
+        let body = format!(
+            "<p>{}</p>\n{}",
+            escape_html(&self.student_line()),
+            to_html_table(self.grades())
+        );
+        html_document("이수구분별 성적", &body)
+    }
+}
+
+/// 성적 증명용 성적표
+///
+/// 전체 성적 요약과 학기별 성적, 과목별 성적을 묶어 CSV, Markdown, HTML로 내보낼 수 있습니다.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Transcript {
+    /// 전체 성적 요약
+    summary: GradeSummary,
+    /// 학기별 성적
+    semesters: Vec<SemesterGrade>,
+    /// 과목별 성적
+    classes: Vec<ClassGrade>,
+}
+
+impl Transcript {
+    /// 새로운 성적표를 만듭니다.
+    pub fn new(
+        summary: GradeSummary,
+        semesters: Vec<SemesterGrade>,
+        classes: Vec<ClassGrade>,
+    ) -> Transcript {
+        Transcript {
+            summary,
+            semesters,
+            classes,
+        }
+    }
+
+    /// 전체 성적 요약
+    pub fn summary(&self) -> &GradeSummary {
+        &self.summary
+    }
+
+    /// 학기별 성적
+    pub fn semesters(&self) -> &[SemesterGrade] {
+        &self.semesters
+    }
+
+    /// 과목별 성적
+    pub fn classes(&self) -> &[ClassGrade] {
+        &self.classes
+    }
+
+    fn classes_of<'a>(
+        &'a self,
+        semester: &'a SemesterGrade,
+    ) -> impl Iterator<Item = &'a ClassGrade> + 'a {
+        self.classes.iter().filter(move |class| {
+            class.year() == semester.year() && class.semester() == semester.semester()
+        })
+    }
+
+    /// 과목별 성적을 CSV 형식으로 변환합니다.
+    pub fn to_csv(&self) -> String {
+        to_csv(&self.classes)
+    }
+
+    /// 성적표를 Markdown 형식으로 변환합니다.
+    pub fn to_markdown(&self) -> String {
+        let mut buf = String::from("# 성적표\n\n## 전체 성적\n\n");
+        buf.push_str(&to_markdown([&self.summary]));
+        for semester in &self.semesters {
+            let _ = write!(
+                buf,
+                "\n## {}학년도 {}\n\n{}\n{}",
+                semester.year(),
+                semester.semester(),
+                to_markdown([semester]),
+                to_markdown(self.classes_of(semester))
+            );
+        }
+        buf
+    }
+
+    /// 성적표를 인쇄 가능한 단독 HTML 문서로 변환합니다.
+    pub fn to_html(&self) -> String {
+        let mut body = String::from("<section>\n<h2>전체 성적</h2>\n");
+        body.push_str(&to_html_table([&self.summary]));
+        body.push_str("</section>\n");
+        for semester in &self.semesters {
+            let _ = write!(
+                body,
+                "<section>\n<h2>{}학년도 {}</h2>\n{}{}</section>\n",
+                semester.year(),
+                escape_html(&semester.semester().to_string()),
+                to_html_table([semester]),
+                to_html_table(self.classes_of(semester))
+            );
+        }
+        html_document("성적표", &body)
+    }
+}
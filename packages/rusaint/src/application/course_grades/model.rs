@@ -1,4 +1,4 @@
-use std::{collections::HashMap, num::ParseIntError, str::FromStr};
+use std::{collections::HashMap, convert::Infallible, fmt::Display, str::FromStr};
 
 use serde::{
     Deserialize, Deserializer, Serialize,
@@ -10,7 +10,7 @@ use crate::{
         deserialize_empty, deserialize_f32_string, deserialize_semester_type,
         deserialize_u32_string,
     },
-    model::SemesterType,
+    model::{LectureCode, SemesterType},
 };
 use wdpe::element::parser::ElementParser;
 use wdpe::{
@@ -19,7 +19,7 @@ use wdpe::{
 };
 
 /// 전체 성적(학적부, 증명)
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug)]
 #[allow(unused)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct GradeSummary {
@@ -87,7 +87,7 @@ impl GradeSummary {
 }
 
 /// 학기별 성적
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[allow(unused)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct SemesterGrade {
@@ -200,6 +200,11 @@ impl SemesterGrade {
         self.semester
     }
 
+    /// 신청학점
+    pub fn attempted_credits(&self) -> f32 {
+        self.attempted_credits
+    }
+
     /// 취득학점
     pub fn earned_credits(&self) -> f32 {
         self.earned_credits
@@ -269,7 +274,7 @@ impl<'body> FromSapTable<'body> for SemesterGrade {
 }
 
 /// 과목별 성적
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[allow(unused)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct ClassGrade {
@@ -278,7 +283,7 @@ pub struct ClassGrade {
     /// 이수학기
     semester: SemesterType,
     /// 과목코드
-    code: String,
+    code: LectureCode,
     /// 과목명
     class_name: String,
     /// 과목학점
@@ -286,11 +291,11 @@ pub struct ClassGrade {
     /// 성적
     score: ClassScore,
     /// 등급
-    rank: String,
+    rank: LetterGrade,
     /// 교수명
     professor: String,
     /// 상세성적
-    detail: Option<HashMap<String, f32>>,
+    detail: Option<ClassGradeDetail>,
 }
 
 impl ClassGrade {
@@ -298,13 +303,13 @@ impl ClassGrade {
     pub(crate) fn new(
         year: u32,
         semester: SemesterType,
-        code: String,
+        code: LectureCode,
         class_name: String,
         grade_points: f32,
         score: ClassScore,
-        rank: String,
+        rank: LetterGrade,
         professor: String,
-        detail: Option<HashMap<String, f32>>,
+        detail: Option<ClassGradeDetail>,
     ) -> ClassGrade {
         ClassGrade {
             year,
@@ -330,8 +335,8 @@ impl ClassGrade {
     }
 
     /// 과목코드
-    pub fn code(&self) -> &str {
-        self.code.as_ref()
+    pub fn code(&self) -> &LectureCode {
+        &self.code
     }
 
     /// 과목명
@@ -346,12 +351,12 @@ impl ClassGrade {
 
     /// 성적
     pub fn score(&self) -> ClassScore {
-        self.score
+        self.score.clone()
     }
 
     /// 등급
-    pub fn rank(&self) -> &str {
-        self.rank.as_ref()
+    pub fn rank(&self) -> &LetterGrade {
+        &self.rank
     }
 
     /// 교수명
@@ -360,7 +365,7 @@ impl ClassGrade {
     }
 
     /// 상세성적
-    pub fn detail(&self) -> Option<&HashMap<String, f32>> {
+    pub fn detail(&self) -> Option<&ClassGradeDetail> {
         self.detail.as_ref()
     }
 }
@@ -383,7 +388,9 @@ pub enum CourseType {
 }
 
 /// 과목 점수
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+///
+/// 해석할 수 없는 점수(e.g. 소수점 점수)는 [`ClassScore::Unknown`]으로 보존됩니다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[allow(unused)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum ClassScore {
@@ -395,21 +402,186 @@ pub enum ClassScore {
     Score(u32),
     /// 성적 없음
     Empty,
+    /// 알 수 없는 점수
+    Unknown(String),
 }
 
 impl FromStr for ClassScore {
-    type Err = ParseIntError;
+    type Err = Infallible;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
         Ok(match s {
             "P" => Self::Pass,
             "F" => Self::Failed,
             "" => Self::Empty,
-            _ => Self::Score(s.parse::<u32>()?),
+            _ => s
+                .parse::<u32>()
+                .map(Self::Score)
+                .unwrap_or_else(|_| Self::Unknown(s.to_string())),
+        })
+    }
+}
+
+impl Display for ClassScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "P"),
+            Self::Failed => write!(f, "F"),
+            Self::Score(score) => write!(f, "{score}"),
+            Self::Empty => Ok(()),
+            Self::Unknown(score) => write!(f, "{score}"),
+        }
+    }
+}
+
+/// 과목 등급
+///
+/// 문자열로 직렬화되며, 알 수 없는 등급은 [`LetterGrade::Unknown`]으로 보존됩니다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", from = "String")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum LetterGrade {
+    /// A+ (4.5)
+    APlus,
+    /// A0 (4.3)
+    AZero,
+    /// A- (4.0)
+    AMinus,
+    /// B+ (3.5)
+    BPlus,
+    /// B0 (3.3)
+    BZero,
+    /// B- (3.0)
+    BMinus,
+    /// C+ (2.5)
+    CPlus,
+    /// C0 (2.3)
+    CZero,
+    /// C- (2.0)
+    CMinus,
+    /// D+ (1.5)
+    DPlus,
+    /// D0 (1.3)
+    DZero,
+    /// D- (1.0)
+    DMinus,
+    /// F (0.0)
+    F,
+    /// P/F 과목의 Pass
+    Pass,
+    /// P/F 과목의 Fail
+    Fail,
+    /// 수강철회
+    Withdrawn,
+    /// 미완(I)
+    Incomplete,
+    /// 등급 없음
+    Empty,
+    /// 알 수 없는 등급
+    Unknown(String),
+}
+
+impl LetterGrade {
+    /// 평점 계산에 사용되는 등급별 평점을 반환합니다. 평점 계산에 포함되지 않는 등급은 `None`을 반환합니다.
+    pub fn grade_point(&self) -> Option<f32> {
+        Some(match self {
+            Self::APlus => 4.5,
+            Self::AZero => 4.3,
+            Self::AMinus => 4.0,
+            Self::BPlus => 3.5,
+            Self::BZero => 3.3,
+            Self::BMinus => 3.0,
+            Self::CPlus => 2.5,
+            Self::CZero => 2.3,
+            Self::CMinus => 2.0,
+            Self::DPlus => 1.5,
+            Self::DZero => 1.3,
+            Self::DMinus => 1.0,
+            Self::F => 0.0,
+            _ => return None,
+        })
+    }
+
+    /// P/F 과목의 등급인지 여부
+    pub fn is_pass_fail(&self) -> bool {
+        matches!(self, Self::Pass | Self::Fail)
+    }
+
+    /// 학점을 취득한 등급인지 여부
+    pub fn is_earned(&self) -> bool {
+        self.grade_point().is_some_and(|point| point > 0.0) || *self == Self::Pass
+    }
+}
+
+impl FromStr for LetterGrade {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "A+" => Self::APlus,
+            "A0" | "A" => Self::AZero,
+            "A-" => Self::AMinus,
+            "B+" => Self::BPlus,
+            "B0" | "B" => Self::BZero,
+            "B-" => Self::BMinus,
+            "C+" => Self::CPlus,
+            "C0" | "C" => Self::CZero,
+            "C-" => Self::CMinus,
+            "D+" => Self::DPlus,
+            "D0" | "D" => Self::DZero,
+            "D-" => Self::DMinus,
+            "F" => Self::F,
+            "P" | "Pass" => Self::Pass,
+            "NP" | "Fail" => Self::Fail,
+            "W" | "철회" => Self::Withdrawn,
+            "I" | "미완" => Self::Incomplete,
+            "" => Self::Empty,
+            other => Self::Unknown(other.to_string()),
         })
     }
 }
 
+impl Display for LetterGrade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Self::APlus => "A+",
+            Self::AZero => "A0",
+            Self::AMinus => "A-",
+            Self::BPlus => "B+",
+            Self::BZero => "B0",
+            Self::BMinus => "B-",
+            Self::CPlus => "C+",
+            Self::CZero => "C0",
+            Self::CMinus => "C-",
+            Self::DPlus => "D+",
+            Self::DZero => "D0",
+            Self::DMinus => "D-",
+            Self::F => "F",
+            Self::Pass => "P",
+            Self::Fail => "NP",
+            Self::Withdrawn => "W",
+            Self::Incomplete => "I",
+            Self::Empty => "",
+            Self::Unknown(grade) => grade,
+        };
+        write!(f, "{str}")
+    }
+}
+
+impl From<String> for LetterGrade {
+    fn from(value: String) -> Self {
+        let Ok(grade) = value.parse();
+        grade
+    }
+}
+
+impl From<LetterGrade> for String {
+    fn from(value: LetterGrade) -> Self {
+        value.to_string()
+    }
+}
+
 /// 이수구분별 성적 조회 결과
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
@@ -485,15 +657,15 @@ pub struct ClassGradeItem {
     /// 학기
     semester: String,
     /// 과목코드
-    course_code: String,
+    course_code: LectureCode,
     /// 과목명
     course_name: String,
-    /// 학점
-    credits: String,
-    /// 성적 점수 (e.g., "100")
-    score: String,
-    /// 성적 등급 (e.g., "A+")
-    grade: String,
+    /// 학점 (해석할 수 없다면 `None`)
+    credits: Option<f32>,
+    /// 성적 점수
+    score: ClassScore,
+    /// 성적 등급
+    grade: LetterGrade,
     /// 비고
     note: String,
 }
@@ -515,7 +687,7 @@ impl ClassGradeItem {
     }
 
     /// 과목코드
-    pub fn course_code(&self) -> &str {
+    pub fn course_code(&self) -> &LectureCode {
         &self.course_code
     }
 
@@ -524,18 +696,18 @@ impl ClassGradeItem {
         &self.course_name
     }
 
-    /// 학점
-    pub fn credits(&self) -> &str {
-        &self.credits
+    /// 학점 (해석할 수 없다면 `None`)
+    pub fn credits(&self) -> Option<f32> {
+        self.credits
     }
 
     /// 성적 점수
-    pub fn score(&self) -> &str {
-        &self.score
+    pub fn score(&self) -> ClassScore {
+        self.score.clone()
     }
 
     /// 성적 등급
-    pub fn grade(&self) -> &str {
+    pub fn grade(&self) -> &LetterGrade {
         &self.grade
     }
 
@@ -588,16 +760,21 @@ impl GradesByClassification {
         // ITAB — 이수구분별 과목 성적
         let grades: Vec<ClassGradeItem> = find_dataset(datasets, "ITAB")
             .iter()
-            .map(|row| ClassGradeItem {
-                classification: get_string_field(row, "COMPL_TEXT"),
-                year: get_string_field(row, "PERYR"),
-                semester: get_string_field(row, "HUKGI"),
-                course_code: get_string_field(row, "SM_ID"),
-                course_name: get_string_field(row, "SM_TEXT"),
-                credits: get_string_field(row, "CPATTEMP"),
-                score: get_string_field(row, "GRADESYMBOL"),
-                grade: get_string_field(row, "GRADE"),
-                note: get_string_field(row, "BIGO"),
+            .map(|row| {
+                let credits = get_string_field(row, "CPATTEMP").trim().parse().ok();
+                let Ok(score) = get_string_field(row, "GRADESYMBOL").parse::<ClassScore>();
+                let Ok(grade) = get_string_field(row, "GRADE").parse::<LetterGrade>();
+                ClassGradeItem {
+                    classification: get_string_field(row, "COMPL_TEXT"),
+                    year: get_string_field(row, "PERYR"),
+                    semester: get_string_field(row, "HUKGI"),
+                    course_code: LectureCode::new(get_string_field(row, "SM_ID")),
+                    course_name: get_string_field(row, "SM_TEXT"),
+                    credits,
+                    grade,
+                    score,
+                    note: get_string_field(row, "BIGO"),
+                }
             })
             .collect();
 
@@ -613,3 +790,91 @@ impl GradesByClassification {
         })
     }
 }
+
+mod detail;
+mod reconciliation;
+mod snapshot;
+
+pub use detail::{ClassGradeDetail, GradeComponent};
+pub use reconciliation::{
+    DifferenceCategory, GradeReconciliation, SemesterTotals, SummaryDifference, SummaryField,
+    SummaryInconsistency, SummarySource,
+};
+pub use snapshot::{GradeChange, GradeSnapshot};
+
+#[cfg(test)]
+mod tests {
+    use ozra::types::FieldValue;
+
+    use super::{ClassScore, GradesByClassification, LetterGrade};
+
+    #[test]
+    fn letter_grade_round_trip() {
+        for grade in ["A+", "A0", "B-", "D0", "F", "P", "W", ""] {
+            let parsed: LetterGrade = grade.parse().unwrap();
+            assert_eq!(parsed.to_string(), grade);
+        }
+        assert_eq!(
+            "XY".parse::<LetterGrade>().unwrap(),
+            LetterGrade::Unknown("XY".to_string())
+        );
+        let json = serde_json::to_string(&LetterGrade::BPlus).unwrap();
+        assert_eq!(json, "\"B+\"");
+        assert_eq!(
+            serde_json::from_str::<LetterGrade>(&json).unwrap(),
+            LetterGrade::BPlus
+        );
+    }
+
+    #[test]
+    fn letter_grade_points() {
+        assert_eq!(LetterGrade::APlus.grade_point(), Some(4.5));
+        assert_eq!(LetterGrade::CZero.grade_point(), Some(2.3));
+        assert_eq!(LetterGrade::F.grade_point(), Some(0.0));
+        assert_eq!(LetterGrade::Pass.grade_point(), None);
+        assert!(LetterGrade::Pass.is_earned());
+        assert!(!LetterGrade::F.is_earned());
+        for symbol in ["F", "NP", "P", "A+"] {
+            assert_eq!(symbol.parse::<LetterGrade>().unwrap().to_string(), symbol);
+        }
+        assert_ne!("F".parse::<LetterGrade>(), "NP".parse::<LetterGrade>());
+    }
+
+    #[test]
+    fn keeps_rows_with_unparsable_values() {
+        let field =
+            |name: &str, value: &str| (name.to_string(), FieldValue::String(value.to_string()));
+        let row = |code: &str, credits: &str, score: &str, grade: &str| {
+            vec![
+                field("SM_ID", code),
+                field("CPATTEMP", credits),
+                field("GRADESYMBOL", score),
+                field("GRADE", grade),
+            ]
+        };
+        let datasets = vec![
+            (
+                "Shadow_master".to_string(),
+                vec![vec![field("ST_NO", "20211561")]],
+            ),
+            (
+                "ITAB".to_string(),
+                vec![
+                    row("2150001", "3.0", "97", "A+"),
+                    row("2150002", "", "87.5", "B+"),
+                    row("2150003", "2.0", "P", "P"),
+                ],
+            ),
+        ];
+        let grades = GradesByClassification::from_datasets(&datasets).unwrap();
+        assert_eq!(grades.grades().len(), 3);
+        assert_eq!(grades.grades()[0].score(), ClassScore::Score(97));
+        assert_eq!(grades.grades()[1].credits(), None);
+        assert_eq!(
+            grades.grades()[1].score(),
+            ClassScore::Unknown("87.5".to_string())
+        );
+        assert_eq!(grades.grades()[1].score().to_string(), "87.5");
+        assert_eq!(grades.grades()[2].credits(), Some(2.0));
+    }
+}
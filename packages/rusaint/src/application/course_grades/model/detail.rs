@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// 과목의 상세 성적
+///
+/// 알려진 항목(중간고사, 기말고사, 과제, 출석)은 각 필드로, 나머지 항목은 [`ClassGradeDetail::others()`]로 분류됩니다.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ClassGradeDetail {
+    /// 중간고사
+    midterm: Option<GradeComponent>,
+    /// 기말고사
+    final_exam: Option<GradeComponent>,
+    /// 과제
+    assignment: Option<GradeComponent>,
+    /// 출석
+    attendance: Option<GradeComponent>,
+    /// 기타 항목
+    others: Vec<GradeComponent>,
+    /// 원본 항목명과 점수
+    raw: HashMap<String, Option<f32>>,
+}
+
+impl ClassGradeDetail {
+    pub(crate) fn from_labeled(
+        entries: impl IntoIterator<Item = (String, Option<f32>)>,
+    ) -> ClassGradeDetail {
+        let mut detail = ClassGradeDetail {
+            midterm: None,
+            final_exam: None,
+            assignment: None,
+            attendance: None,
+            others: Vec::new(),
+            raw: HashMap::new(),
+        };
+        for (label, score) in entries {
+            detail.raw.insert(label.clone(), score);
+            let component = GradeComponent::from_label(label, score);
+            let slot = match component.name() {
+                name if name.contains("중간") => &mut detail.midterm,
+                name if name.contains("기말") => &mut detail.final_exam,
+                name if ["과제", "레포트", "리포트"]
+                    .iter()
+                    .any(|keyword| name.contains(keyword)) =>
+                {
+                    &mut detail.assignment
+                }
+                name if name.contains("출석") || name.contains("출결") => {
+                    &mut detail.attendance
+                }
+                _ => {
+                    detail.others.push(component);
+                    continue;
+                }
+            };
+            if slot.is_none() {
+                *slot = Some(component);
+            } else {
+                detail.others.push(component);
+            }
+        }
+        detail
+    }
+
+    /// 중간고사
+    pub fn midterm(&self) -> Option<&GradeComponent> {
+        self.midterm.as_ref()
+    }
+
+    /// 기말고사
+    pub fn final_exam(&self) -> Option<&GradeComponent> {
+        self.final_exam.as_ref()
+    }
+
+    /// 과제
+    pub fn assignment(&self) -> Option<&GradeComponent> {
+        self.assignment.as_ref()
+    }
+
+    /// 출석
+    pub fn attendance(&self) -> Option<&GradeComponent> {
+        self.attendance.as_ref()
+    }
+
+    /// 기타 항목
+    pub fn others(&self) -> &[GradeComponent] {
+        &self.others
+    }
+
+    /// 원본 항목명과 점수
+    pub fn raw(&self) -> &HashMap<String, Option<f32>> {
+        &self.raw
+    }
+
+    /// 전체 항목
+    pub fn components(&self) -> impl Iterator<Item = &GradeComponent> {
+        [
+            &self.midterm,
+            &self.final_exam,
+            &self.assignment,
+            &self.attendance,
+        ]
+        .into_iter()
+        .flatten()
+        .chain(self.others.iter())
+    }
+}
+
+/// 상세 성적의 개별 항목
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct GradeComponent {
+    /// 원본 항목명 (e.g., "출석(20.000)")
+    label: String,
+    /// 항목 이름 (e.g., "출석")
+    name: String,
+    /// 반영비율
+    weight: Option<f32>,
+    /// 점수 (비어 있다면 `None`)
+    score: Option<f32>,
+}
+
+impl GradeComponent {
+    fn from_label(label: String, score: Option<f32>) -> GradeComponent {
+        let trimmed = label.trim();
+        let (name, weight) = match trimmed
+            .strip_suffix(')')
+            .and_then(|rest| rest.rsplit_once('('))
+        {
+            Some((name, weight)) => (
+                name.trim().to_string(),
+                weight.trim().trim_end_matches('%').parse().ok(),
+            ),
+            None => (trimmed.to_string(), None),
+        };
+        GradeComponent {
+            label,
+            name,
+            weight,
+            score,
+        }
+    }
+
+    /// 원본 항목명
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// 항목 이름
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 반영비율
+    pub fn weight(&self) -> Option<f32> {
+        self.weight
+    }
+
+    /// 점수
+    pub fn score(&self) -> Option<f32> {
+        self.score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_components() {
+        let detail = ClassGradeDetail::from_labeled([
+            ("출석(20.000)".to_string(), Some(20.0)),
+            ("중간고사(30.000)".to_string(), Some(30.0)),
+            ("과제(20.000)".to_string(), None),
+            ("기말고사(30.000)".to_string(), Some(28.0)),
+            ("발표".to_string(), Some(5.0)),
+        ]);
+        let attendance = detail.attendance().unwrap();
+        assert_eq!(attendance.name(), "출석");
+        assert_eq!(attendance.weight(), Some(20.0));
+        assert_eq!(detail.midterm().unwrap().score(), Some(30.0));
+        assert_eq!(detail.final_exam().unwrap().score(), Some(28.0));
+        assert_eq!(detail.assignment().unwrap().score(), None);
+        assert_eq!(detail.others().len(), 1);
+        assert_eq!(detail.others()[0].weight(), None);
+        assert_eq!(detail.raw().len(), 5);
+        assert_eq!(detail.components().count(), 5);
+    }
+}
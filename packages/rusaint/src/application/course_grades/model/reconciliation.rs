@@ -0,0 +1,446 @@
+use serde::{Deserialize, Serialize};
+
+use super::{GradeSummary, SemesterGrade};
+
+/// 두 값이 같다고 볼 수 있는 최대 차이
+const TOLERANCE: f32 = 0.005;
+/// 반올림 오차로 볼 수 있는 평균 값의 최대 차이
+const ROUNDING_TOLERANCE: f32 = 0.015;
+
+/// 학적부 평점과 증명 평점의 비교 결과
+///
+/// 두 평점의 각 항목별 차이와 추정 원인, 그리고 학기별 성적과 맞지 않는 값을 보고합니다.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct GradeReconciliation {
+    /// 학적부 기준 전체 성적
+    recorded: GradeSummary,
+    /// 증명서 기준 전체 성적
+    certificated: GradeSummary,
+    /// 학기별 성적의 합계
+    semester_totals: SemesterTotals,
+    /// 항목별 차이
+    differences: Vec<SummaryDifference>,
+    /// 일관되지 않은 값
+    inconsistencies: Vec<SummaryInconsistency>,
+}
+
+impl GradeReconciliation {
+    /// 학적부 평점, 증명 평점, 학기별 성적을 비교합니다.
+    pub fn new(
+        recorded: GradeSummary,
+        certificated: GradeSummary,
+        semesters: &[SemesterGrade],
+    ) -> GradeReconciliation {
+        let semester_totals = SemesterTotals::from_semesters(semesters);
+        let differences = SummaryField::ALL
+            .iter()
+            .filter_map(|&field| {
+                let recorded_value = field.value_of(&recorded);
+                let certificated_value = field.value_of(&certificated);
+                let difference = certificated_value - recorded_value;
+                if difference.abs() <= TOLERANCE {
+                    return None;
+                }
+                Some(SummaryDifference {
+                    field,
+                    recorded: recorded_value,
+                    certificated: certificated_value,
+                    difference,
+                    category: DifferenceCategory::estimate(
+                        field,
+                        difference,
+                        &recorded,
+                        &certificated,
+                    ),
+                })
+            })
+            .collect();
+        let mut inconsistencies = Vec::new();
+        for (source, summary) in [
+            (SummarySource::Recorded, &recorded),
+            (SummarySource::Certificated, &certificated),
+        ] {
+            if summary.earned_credits() > summary.attempted_credits() + TOLERANCE {
+                inconsistencies.push(SummaryInconsistency {
+                    source,
+                    field: SummaryField::EarnedCredits,
+                    expected: summary.attempted_credits(),
+                    actual: summary.earned_credits(),
+                    description: "취득학점이 신청학점보다 많습니다".to_string(),
+                });
+            }
+            if !(0.0..=4.5).contains(&summary.grade_points_average()) {
+                inconsistencies.push(SummaryInconsistency {
+                    source,
+                    field: SummaryField::GradePointsAverage,
+                    expected: 4.5,
+                    actual: summary.grade_points_average(),
+                    description: "평점평균이 0.0 ~ 4.5 범위를 벗어났습니다".to_string(),
+                });
+            }
+        }
+        if !semesters.is_empty() {
+            for (field, expected) in [
+                (
+                    SummaryField::AttemptedCredits,
+                    semester_totals.attempted_credits,
+                ),
+                (SummaryField::EarnedCredits, semester_totals.earned_credits),
+                (
+                    SummaryField::PfEarnedCredits,
+                    semester_totals.pf_earned_credits,
+                ),
+                (
+                    SummaryField::GradePointsSum,
+                    semester_totals.grade_points_sum,
+                ),
+            ] {
+                let actual = field.value_of(&recorded);
+                if (expected - actual).abs() > TOLERANCE {
+                    inconsistencies.push(SummaryInconsistency {
+                        source: SummarySource::Semesters,
+                        field,
+                        expected,
+                        actual,
+                        description: "학기별 성적의 합계가 학적부 평점과 다릅니다".to_string(),
+                    });
+                }
+            }
+        }
+        GradeReconciliation {
+            recorded,
+            certificated,
+            semester_totals,
+            differences,
+            inconsistencies,
+        }
+    }
+
+    /// 학적부 기준 전체 성적
+    pub fn recorded(&self) -> &GradeSummary {
+        &self.recorded
+    }
+
+    /// 증명서 기준 전체 성적
+    pub fn certificated(&self) -> &GradeSummary {
+        &self.certificated
+    }
+
+    /// 학기별 성적의 합계
+    pub fn semester_totals(&self) -> &SemesterTotals {
+        &self.semester_totals
+    }
+
+    /// 항목별 차이
+    pub fn differences(&self) -> &[SummaryDifference] {
+        &self.differences
+    }
+
+    /// 일관되지 않은 값
+    pub fn inconsistencies(&self) -> &[SummaryInconsistency] {
+        &self.inconsistencies
+    }
+
+    /// 일관되지 않은 값이 없는지 여부
+    pub fn is_consistent(&self) -> bool {
+        self.inconsistencies.is_empty()
+    }
+}
+
+/// 학기별 성적의 합계
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SemesterTotals {
+    /// 신청학점 합계
+    attempted_credits: f32,
+    /// 취득학점 합계
+    earned_credits: f32,
+    /// P/F학점 합계
+    pf_earned_credits: f32,
+    /// 평점계 합계
+    grade_points_sum: f32,
+}
+
+impl SemesterTotals {
+    fn from_semesters(semesters: &[SemesterGrade]) -> SemesterTotals {
+        semesters.iter().fold(
+            SemesterTotals {
+                attempted_credits: 0.0,
+                earned_credits: 0.0,
+                pf_earned_credits: 0.0,
+                grade_points_sum: 0.0,
+            },
+            |totals, semester| SemesterTotals {
+                attempted_credits: totals.attempted_credits + semester.attempted_credits(),
+                earned_credits: totals.earned_credits + semester.earned_credits(),
+                pf_earned_credits: totals.pf_earned_credits + semester.pf_earned_credits(),
+                grade_points_sum: totals.grade_points_sum + semester.grade_points_sum(),
+            },
+        )
+    }
+
+    /// 신청학점 합계
+    pub fn attempted_credits(&self) -> f32 {
+        self.attempted_credits
+    }
+
+    /// 취득학점 합계
+    pub fn earned_credits(&self) -> f32 {
+        self.earned_credits
+    }
+
+    /// P/F학점 합계
+    pub fn pf_earned_credits(&self) -> f32 {
+        self.pf_earned_credits
+    }
+
+    /// 평점계 합계
+    pub fn grade_points_sum(&self) -> f32 {
+        self.grade_points_sum
+    }
+}
+
+/// 전체 성적의 항목
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum SummaryField {
+    /// 신청학점
+    AttemptedCredits,
+    /// 취득학점
+    EarnedCredits,
+    /// 평점계
+    GradePointsSum,
+    /// 평점평균
+    GradePointsAverage,
+    /// 산술평균
+    ArithmeticMean,
+    /// P/F 학점계
+    PfEarnedCredits,
+}
+
+impl SummaryField {
+    const ALL: [SummaryField; 6] = [
+        SummaryField::AttemptedCredits,
+        SummaryField::EarnedCredits,
+        SummaryField::GradePointsSum,
+        SummaryField::GradePointsAverage,
+        SummaryField::ArithmeticMean,
+        SummaryField::PfEarnedCredits,
+    ];
+
+    fn value_of(self, summary: &GradeSummary) -> f32 {
+        match self {
+            SummaryField::AttemptedCredits => summary.attempted_credits(),
+            SummaryField::EarnedCredits => summary.earned_credits(),
+            SummaryField::GradePointsSum => summary.grade_points_sum(),
+            SummaryField::GradePointsAverage => summary.grade_points_average(),
+            SummaryField::ArithmeticMean => summary.arithmetic_mean(),
+            SummaryField::PfEarnedCredits => summary.pf_earned_credits(),
+        }
+    }
+}
+
+/// 학적부 평점과 증명 평점의 항목별 차이
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SummaryDifference {
+    /// 항목
+    field: SummaryField,
+    /// 학적부 기준 값
+    recorded: f32,
+    /// 증명서 기준 값
+    certificated: f32,
+    /// 증명서 기준 값 - 학적부 기준 값
+    difference: f32,
+    /// 추정 원인
+    category: DifferenceCategory,
+}
+
+impl SummaryDifference {
+    /// 항목
+    pub fn field(&self) -> SummaryField {
+        self.field
+    }
+
+    /// 학적부 기준 값
+    pub fn recorded(&self) -> f32 {
+        self.recorded
+    }
+
+    /// 증명서 기준 값
+    pub fn certificated(&self) -> f32 {
+        self.certificated
+    }
+
+    /// 증명서 기준 값 - 학적부 기준 값
+    pub fn difference(&self) -> f32 {
+        self.difference
+    }
+
+    /// 추정 원인
+    pub fn category(&self) -> DifferenceCategory {
+        self.category
+    }
+}
+
+/// 평점 차이의 추정 원인
+///
+/// u-saint는 차이의 원인을 제공하지 않으므로, 항목과 차이의 방향으로 추정한 값입니다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum DifferenceCategory {
+    /// P/F 과목 학점의 반영 방식 차이
+    PassFail,
+    /// 재수강으로 인해 증명서에서 제외된 이전 수강 기록
+    Retake,
+    /// 편입, 학점 인정 등으로 증명서에만 반영된 학점
+    Transfer,
+    /// 학점 차이로 인해 함께 달라진 평균 값
+    Derived,
+    /// 평균 값의 반올림 오차
+    Rounding,
+    /// 원인을 추정할 수 없음
+    Unexplained,
+}
+
+impl DifferenceCategory {
+    fn estimate(
+        field: SummaryField,
+        difference: f32,
+        recorded: &GradeSummary,
+        certificated: &GradeSummary,
+    ) -> DifferenceCategory {
+        let pf_difference = certificated.pf_earned_credits() - recorded.pf_earned_credits();
+        let credits_differ =
+            (certificated.attempted_credits() - recorded.attempted_credits()).abs() > TOLERANCE
+                || (certificated.earned_credits() - recorded.earned_credits()).abs() > TOLERANCE;
+        match field {
+            SummaryField::PfEarnedCredits => DifferenceCategory::PassFail,
+            SummaryField::EarnedCredits | SummaryField::AttemptedCredits
+                if pf_difference.abs() > TOLERANCE
+                    && (difference - pf_difference).abs() <= TOLERANCE =>
+            {
+                DifferenceCategory::PassFail
+            }
+            SummaryField::AttemptedCredits
+            | SummaryField::EarnedCredits
+            | SummaryField::GradePointsSum => {
+                if difference < 0.0 {
+                    DifferenceCategory::Retake
+                } else {
+                    DifferenceCategory::Transfer
+                }
+            }
+            SummaryField::GradePointsAverage | SummaryField::ArithmeticMean => {
+                if credits_differ {
+                    DifferenceCategory::Derived
+                } else if difference.abs() <= ROUNDING_TOLERANCE {
+                    DifferenceCategory::Rounding
+                } else {
+                    DifferenceCategory::Unexplained
+                }
+            }
+        }
+    }
+}
+
+/// 일관되지 않은 값이 발견된 출처
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum SummarySource {
+    /// 학적부 평점
+    Recorded,
+    /// 증명 평점
+    Certificated,
+    /// 학기별 성적
+    Semesters,
+}
+
+/// 일관되지 않은 값
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SummaryInconsistency {
+    /// 출처
+    source: SummarySource,
+    /// 항목
+    field: SummaryField,
+    /// 기대한 값
+    expected: f32,
+    /// 실제 값
+    actual: f32,
+    /// 설명
+    description: String,
+}
+
+impl SummaryInconsistency {
+    /// 출처
+    pub fn source(&self) -> SummarySource {
+        self.source
+    }
+
+    /// 항목
+    pub fn field(&self) -> SummaryField {
+        self.field
+    }
+
+    /// 기대한 값
+    pub fn expected(&self) -> f32 {
+        self.expected
+    }
+
+    /// 실제 값
+    pub fn actual(&self) -> f32 {
+        self.actual
+    }
+
+    /// 설명
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn categorizes_differences() {
+        let recorded = GradeSummary::new(130.0, 127.0, 400.0, 3.5, 90.0, 3.0);
+        let certificated = GradeSummary::new(127.0, 124.0, 390.0, 3.6, 90.01, 3.0);
+        let reconciliation = GradeReconciliation::new(recorded, certificated, &[]);
+        let category = |field| {
+            reconciliation
+                .differences()
+                .iter()
+                .find(|difference| difference.field() == field)
+                .map(SummaryDifference::category)
+        };
+        assert_eq!(
+            category(SummaryField::AttemptedCredits),
+            Some(DifferenceCategory::Retake)
+        );
+        assert_eq!(
+            category(SummaryField::GradePointsAverage),
+            Some(DifferenceCategory::Derived)
+        );
+        assert_eq!(category(SummaryField::PfEarnedCredits), None);
+        assert!(reconciliation.is_consistent());
+    }
+
+    #[test]
+    fn flags_inconsistent_values() {
+        let recorded = GradeSummary::new(10.0, 12.0, 40.0, 4.0, 90.0, 0.0);
+        let certificated = GradeSummary::new(10.0, 10.0, 40.0, 4.8, 90.0, 0.0);
+        let reconciliation = GradeReconciliation::new(recorded, certificated, &[]);
+        assert_eq!(reconciliation.inconsistencies().len(), 2);
+        assert_eq!(
+            reconciliation.inconsistencies()[0].source(),
+            SummarySource::Recorded
+        );
+        assert_eq!(
+            reconciliation.inconsistencies()[1].field(),
+            SummaryField::GradePointsAverage
+        );
+    }
+}
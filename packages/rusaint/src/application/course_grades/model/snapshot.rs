@@ -0,0 +1,292 @@
+use std::io::{BufRead, Write};
+
+use serde::{Deserialize, Serialize};
+
+use super::{ClassGrade, ClassScore, SemesterGrade};
+use crate::{ApplicationError, RusaintError, model::SemesterType};
+
+/// 특정 학기의 성적 스냅샷
+///
+/// 이전에 저장한 스냅샷과 새로 가져온 스냅샷을 [`GradeSnapshot::changes_since()`]로 비교하여
+/// 새로 게시되었거나 변경된 성적을 찾을 수 있습니다.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct GradeSnapshot {
+    /// 학년도
+    year: u32,
+    /// 학기
+    semester: SemesterType,
+    /// 학기별 성적 (아직 학기 성적이 게시되지 않았다면 `None`)
+    semester_grade: Option<SemesterGrade>,
+    /// 과목별 성적
+    classes: Vec<ClassGrade>,
+}
+
+impl GradeSnapshot {
+    /// 새로운 스냅샷을 만듭니다.
+    pub fn new(
+        year: u32,
+        semester: SemesterType,
+        semester_grade: Option<SemesterGrade>,
+        classes: Vec<ClassGrade>,
+    ) -> GradeSnapshot {
+        GradeSnapshot {
+            year,
+            semester,
+            semester_grade,
+            classes,
+        }
+    }
+
+    /// 학년도
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 학기
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 학기별 성적
+    pub fn semester_grade(&self) -> Option<&SemesterGrade> {
+        self.semester_grade.as_ref()
+    }
+
+    /// 과목별 성적
+    pub fn classes(&self) -> &[ClassGrade] {
+        &self.classes
+    }
+
+    /// 스냅샷을 json 형식으로 저장합니다.
+    pub fn save_to_json<W: Write>(&self, writer: &mut W) -> Result<(), RusaintError> {
+        serde_json::to_writer(writer, self)
+            .map_err(|e| ApplicationError::SnapshotError(e.to_string()))?;
+        Ok(())
+    }
+
+    /// 스냅샷을 json 문자열로 변환합니다.
+    pub fn to_json(&self) -> Result<String, RusaintError> {
+        Ok(serde_json::to_string(self)
+            .map_err(|e| ApplicationError::SnapshotError(e.to_string()))?)
+    }
+
+    /// json 형식으로 저장된 스냅샷을 읽습니다.
+    pub fn from_json<R: BufRead>(reader: R) -> Result<GradeSnapshot, RusaintError> {
+        Ok(serde_json::from_reader(reader)
+            .map_err(|e| ApplicationError::SnapshotError(e.to_string()))?)
+    }
+
+    /// 이전 스냅샷과 비교하여 새로 게시되었거나 변경된 성적을 반환합니다.
+    ///
+    /// 과목은 과목코드를 기준으로 비교합니다. 두 스냅샷의 학기가 다르다면 현재 스냅샷의 모든 성적을 새로운 성적으로 간주합니다.
+    /// 상세 성적은 두 스냅샷 모두 상세 성적을 포함한 경우에만 비교합니다.
+    pub fn changes_since(&self, previous: &GradeSnapshot) -> Vec<GradeChange> {
+        let empty = GradeSnapshot::new(self.year, self.semester, None, Vec::with_capacity(0));
+        let previous = if previous.year == self.year && previous.semester == self.semester {
+            previous
+        } else {
+            &empty
+        };
+        let mut changes = Vec::new();
+        for class in &self.classes {
+            let Some(before) = previous
+                .classes
+                .iter()
+                .find(|prev| prev.code() == class.code())
+            else {
+                changes.push(GradeChange::ClassAdded {
+                    class: class.clone(),
+                });
+                continue;
+            };
+            if before.score() != class.score() || before.rank() != class.rank() {
+                if before.score() == ClassScore::Empty {
+                    changes.push(GradeChange::GradePosted {
+                        class: class.clone(),
+                    });
+                } else {
+                    changes.push(GradeChange::GradeChanged {
+                        before: before.clone(),
+                        after: class.clone(),
+                    });
+                }
+            } else if let (Some(before_detail), Some(after_detail)) =
+                (before.detail(), class.detail())
+                && before_detail != after_detail
+            {
+                changes.push(GradeChange::DetailChanged {
+                    before: before.clone(),
+                    after: class.clone(),
+                });
+            }
+        }
+        for before in &previous.classes {
+            if !self
+                .classes
+                .iter()
+                .any(|class| class.code() == before.code())
+            {
+                changes.push(GradeChange::ClassRemoved {
+                    class: before.clone(),
+                });
+            }
+        }
+        match (&previous.semester_grade, &self.semester_grade) {
+            (None, Some(after)) => changes.push(GradeChange::SemesterGradePosted {
+                semester_grade: after.clone(),
+            }),
+            (Some(before), Some(after)) if before != after => {
+                changes.push(GradeChange::SemesterGradeChanged {
+                    before: before.clone(),
+                    after: after.clone(),
+                })
+            }
+            _ => {}
+        }
+        changes
+    }
+}
+
+/// 두 성적 스냅샷 사이의 변경 사항
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum GradeChange {
+    /// 이전 스냅샷에 없던 과목이 추가됨
+    ClassAdded {
+        /// 추가된 과목 성적
+        class: ClassGrade,
+    },
+    /// 비어 있던 과목 성적이 새로 게시됨
+    GradePosted {
+        /// 게시된 과목 성적
+        class: ClassGrade,
+    },
+    /// 과목의 성적(점수 또는 등급)이 변경됨
+    GradeChanged {
+        /// 변경 전 과목 성적
+        before: ClassGrade,
+        /// 변경 후 과목 성적
+        after: ClassGrade,
+    },
+    /// 과목의 상세 성적이 변경됨
+    DetailChanged {
+        /// 변경 전 과목 성적
+        before: ClassGrade,
+        /// 변경 후 과목 성적
+        after: ClassGrade,
+    },
+    /// 이전 스냅샷에 있던 과목이 사라짐
+    ClassRemoved {
+        /// 사라진 과목 성적
+        class: ClassGrade,
+    },
+    /// 학기 성적이 새로 게시됨
+    SemesterGradePosted {
+        /// 게시된 학기 성적
+        semester_grade: SemesterGrade,
+    },
+    /// 학기 성적이 변경됨
+    SemesterGradeChanged {
+        /// 변경 전 학기 성적
+        before: SemesterGrade,
+        /// 변경 후 학기 성적
+        after: SemesterGrade,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::course_grades::model::ClassGradeDetail;
+
+    fn class(code: &str, score: ClassScore, rank: &str) -> ClassGrade {
+        class_with_detail(code, score, rank, None)
+    }
+
+    fn class_with_detail(
+        code: &str,
+        score: ClassScore,
+        rank: &str,
+        midterm: Option<f32>,
+    ) -> ClassGrade {
+        ClassGrade::new(
+            2024,
+            SemesterType::Two,
+            code.into(),
+            "과목".to_string(),
+            3.0,
+            score,
+            rank.parse().unwrap(),
+            "교수".to_string(),
+            midterm.map(|midterm| {
+                ClassGradeDetail::from_labeled([("중간고사".to_string(), Some(midterm))])
+            }),
+        )
+    }
+
+    #[test]
+    fn detects_posted_and_added_grades() {
+        let previous = GradeSnapshot::new(
+            2024,
+            SemesterType::Two,
+            None,
+            vec![
+                class("A", ClassScore::Empty, ""),
+                class("B", ClassScore::Score(90), "A0"),
+            ],
+        );
+        let current = GradeSnapshot::new(
+            2024,
+            SemesterType::Two,
+            None,
+            vec![
+                class("A", ClassScore::Score(97), "A+"),
+                class("B", ClassScore::Score(90), "A0"),
+                class("C", ClassScore::Pass, "P"),
+            ],
+        );
+        let changes = current.changes_since(&previous);
+        assert_eq!(changes.len(), 2);
+        assert!(matches!(&changes[0], GradeChange::GradePosted { class } if class.code() == "A"));
+        assert!(matches!(&changes[1], GradeChange::ClassAdded { class } if class.code() == "C"));
+    }
+
+    #[test]
+    fn different_semester_is_treated_as_new() {
+        let previous = GradeSnapshot::new(
+            2024,
+            SemesterType::One,
+            None,
+            vec![class("A", ClassScore::Score(97), "A+")],
+        );
+        let current = GradeSnapshot::new(
+            2024,
+            SemesterType::Two,
+            None,
+            vec![class("A", ClassScore::Score(97), "A+")],
+        );
+        let changes = current.changes_since(&previous);
+        assert!(matches!(&changes[..], [GradeChange::ClassAdded { .. }]));
+        let restored = GradeSnapshot::from_json(current.to_json().unwrap().as_bytes()).unwrap();
+        assert!(current.changes_since(&restored).is_empty());
+    }
+
+    #[test]
+    fn compares_details_only_when_both_present() {
+        let snapshot = |midterm| {
+            GradeSnapshot::new(
+                2024,
+                SemesterType::Two,
+                None,
+                vec![class_with_detail("A", ClassScore::Score(97), "A+", midterm)],
+            )
+        };
+        let without = snapshot(None);
+        let with = snapshot(Some(30.0));
+        assert!(with.changes_since(&without).is_empty());
+        assert!(without.changes_since(&with).is_empty());
+        let changes = snapshot(Some(35.0)).changes_since(&with);
+        assert!(matches!(&changes[..], [GradeChange::DetailChanged { .. }]));
+    }
+}
@@ -1,9 +1,12 @@
 use crate::ApplicationError;
-use crate::application::course_registration_status::model::RegisteredLecture;
+use crate::application::course_registration_status::model::{
+    RegisteredLecture, RegistrationHistory, RegistrationSemester,
+};
+use crate::application::current_semester::CurrentSemester;
 use crate::application::utils::oz::{
-    extract_oz_url_from_script_calls, fetch_data_module, parse_oz_url_params,
+    OzUrlParams, extract_oz_url_from_script_calls, fetch_data_module, parse_oz_url_params,
 };
-use crate::application::utils::semester::get_selected_semester;
+use crate::application::utils::semester::{get_selected_semester, semesters_between};
 use crate::client::{USaintApplication, USaintClient};
 use crate::{RusaintError, model::SemesterType};
 use wdpe::command::WebDynproCommandExecutor;
@@ -74,6 +77,7 @@ impl<'app> CourseRegistrationStatusApplication {
 
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
+    /// 현재 학기는 [`current_semester`](crate::application::current_semester::current_semester)로 확인하세요.
     pub fn get_selected_semester(&self) -> Result<(u32, SemesterType), RusaintError> {
         Ok(get_selected_semester(
             &self.client,
@@ -89,12 +93,7 @@ impl<'app> CourseRegistrationStatusApplication {
         BUTTON_PRINT: Button<'app> = "ZCMW2110.ID_0001:VIW_MAIN.BUTTON_PRINT";
     }
 
-    /// 개인이 수강신청한 내역을 학기별로 찾습니다.
-    pub async fn lectures(
-        &mut self,
-        year: u32,
-        semester: SemesterType,
-    ) -> Result<impl Iterator<Item = RegisteredLecture>, RusaintError> {
+    async fn oz_params(&mut self) -> Result<OzUrlParams, RusaintError> {
         let parser = ElementParser::new(self.client.body());
         let button_press_event = parser.read(ButtonPressEventCommand::new(Self::BUTTON_PRINT))?;
         let result = self.client.process_event(true, button_press_event).await?;
@@ -112,7 +111,15 @@ impl<'app> CourseRegistrationStatusApplication {
         };
 
         let oz_url = extract_oz_url_from_script_calls(&script_calls)?;
-        let mut oz_params = parse_oz_url_params(&oz_url)?;
+        parse_oz_url_params(&oz_url)
+    }
+
+    async fn fetch_lectures(
+        &self,
+        oz_params: &mut OzUrlParams,
+        year: u32,
+        semester: SemesterType,
+    ) -> Result<Vec<RegisteredLecture>, RusaintError> {
         let semester_key = Self::semester_to_key(semester).to_string();
         let year_value = year.to_string();
 
@@ -124,11 +131,42 @@ impl<'app> CourseRegistrationStatusApplication {
             }
         }
 
-        let response = fetch_data_module(&oz_params, Some(self.client.http_client())).await?;
-        let lectures = RegisteredLecture::from_datasets(&response.datasets)?;
+        let response = fetch_data_module(oz_params, Some(self.client.http_client())).await?;
+        RegisteredLecture::from_datasets(&response.datasets)
+    }
+
+    /// 개인이 수강신청한 내역을 학기별로 찾습니다.
+    pub async fn lectures(
+        &mut self,
+        year: u32,
+        semester: SemesterType,
+    ) -> Result<impl Iterator<Item = RegisteredLecture>, RusaintError> {
+        let mut oz_params = self.oz_params().await?;
+        let lectures = self.fetch_lectures(&mut oz_params, year, semester).await?;
         Ok(lectures.into_iter())
     }
 
+    /// 입학년도부터 현재 학기까지 모든 학기(계절학기 포함)의 수강신청 내역을 가져옵니다.
+    ///
+    /// 현재 학기는 [`CurrentSemester::today`]와 같이 한국 표준시 기준 오늘 날짜로 추정하며, 아직 시작하지 않은 학기는 조회하지 않습니다.
+    ///
+    /// 입학년도는 [`StudentInformation::apply_year`](crate::application::student_information::model::StudentInformation::apply_year)로 확인할 수 있습니다.
+    /// 수강신청 내역이 없는 학기는 결과에서 제외됩니다.
+    pub async fn history(&mut self, apply_year: u32) -> Result<RegistrationHistory, RusaintError> {
+        let current = CurrentSemester::today();
+        let until = (current.year(), current.semester());
+        let mut oz_params = self.oz_params().await?;
+        let mut semesters = Vec::new();
+        for (year, semester) in semesters_between(apply_year, until) {
+            match self.fetch_lectures(&mut oz_params, year, semester).await {
+                Ok(lectures) => semesters.push(RegistrationSemester::new(year, semester, lectures)),
+                Err(RusaintError::ApplicationError(ApplicationError::NoLectureResult)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(RegistrationHistory::new(semesters))
+    }
+
     /// 페이지를 새로고침합니다.
     pub async fn reload(&mut self) -> Result<(), RusaintError> {
         self.client.reload().await?;
@@ -1,7 +1,12 @@
+use chrono::NaiveDateTime;
 use ozra::types::{DataSet, FieldValue};
 use serde::{Deserialize, Serialize};
 
-use crate::{ApplicationError, RusaintError};
+use crate::application::utils::de_with::parse_date_time;
+use crate::{
+    ApplicationError, RusaintError,
+    model::{LectureCode, LectureSection, SemesterType},
+};
 
 /// OZ `ET_BOOKED` 데이터셋 기준 수강신청 과목 정보
 #[allow(unused)]
@@ -19,7 +24,7 @@ pub struct RegisteredLecture {
     /// 교과영역
     pub field: Option<String>,
     /// 과목번호 (`SE_SHORT`)
-    pub code: String,
+    pub code: LectureCode,
     /// 과목명
     pub name: String,
     /// 분반
@@ -46,6 +51,8 @@ pub struct RegisteredLecture {
     pub registration_date: String,
     /// 수강신청시각
     pub registration_time: String,
+    /// 수강신청일시 (`registration_date`와 `registration_time`을 해석한 값)
+    pub registered_at: Option<NaiveDateTime>,
     /// 비고 (`REMARK`)
     pub remark: String,
 }
@@ -70,27 +77,32 @@ impl RegisteredLecture {
     pub fn from_datasets(datasets: &[DataSet]) -> Result<Vec<Self>, RusaintError> {
         let lectures: Vec<Self> = find_dataset(datasets, "ET_BOOKED")
             .iter()
-            .map(|row| Self {
-                syllabus: None,
-                category: get_string_field(row, "CATEGORY"),
-                sub_category: None,
-                abeek_info: Some(get_string_field(row, "ABEEK_INFO")).filter(|s| !s.is_empty()),
-                field: None,
-                code: get_string_field(row, "SE_SHORT"),
-                name: get_string_field(row, "SE_STEXT"),
-                division: Some(get_string_field(row, "BUNBAN")).filter(|s| !s.is_empty()),
-                professor: get_string_field(row, "PROF_NM"),
-                time_points: get_string_field(row, "TIME_CREDIT"),
-                schedule_room: get_string_field(row, "LEC_TIME_ROOM"),
-                sm_objid: get_string_field(row, "SM_OBJID"),
-                se_objid: get_string_field(row, "SE_OBJID"),
-                full_name: get_string_field(row, "LONG_NAME"),
-                multi_major_info: get_string_field(row, "MULTI"),
-                program_code: get_string_field(row, "PROGC_VAR"),
-                program_title: get_string_field(row, "PROGC_VART"),
-                registration_date: get_string_field(row, "BOOKDATE"),
-                registration_time: get_string_field(row, "BOOKTIME"),
-                remark: get_string_field(row, "REMARK"),
+            .map(|row| {
+                let registration_date = get_string_field(row, "BOOKDATE");
+                let registration_time = get_string_field(row, "BOOKTIME");
+                Self {
+                    syllabus: None,
+                    category: get_string_field(row, "CATEGORY"),
+                    sub_category: None,
+                    abeek_info: Some(get_string_field(row, "ABEEK_INFO")).filter(|s| !s.is_empty()),
+                    field: None,
+                    code: LectureCode::new(get_string_field(row, "SE_SHORT")),
+                    name: get_string_field(row, "SE_STEXT"),
+                    division: Some(get_string_field(row, "BUNBAN")).filter(|s| !s.is_empty()),
+                    professor: get_string_field(row, "PROF_NM"),
+                    time_points: get_string_field(row, "TIME_CREDIT"),
+                    schedule_room: get_string_field(row, "LEC_TIME_ROOM"),
+                    sm_objid: get_string_field(row, "SM_OBJID"),
+                    se_objid: get_string_field(row, "SE_OBJID"),
+                    full_name: get_string_field(row, "LONG_NAME"),
+                    multi_major_info: get_string_field(row, "MULTI"),
+                    program_code: get_string_field(row, "PROGC_VAR"),
+                    program_title: get_string_field(row, "PROGC_VART"),
+                    registered_at: parse_date_time(&registration_date, &registration_time),
+                    registration_date,
+                    registration_time,
+                    remark: get_string_field(row, "REMARK"),
+                }
             })
             .collect();
 
@@ -100,4 +112,80 @@ impl RegisteredLecture {
 
         Ok(lectures)
     }
+
+    /// 과목번호와 분반
+    pub fn section(&self) -> LectureSection {
+        LectureSection::new(self.code.clone(), self.division.clone())
+    }
+}
+
+/// 한 학기의 수강신청 내역
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct RegistrationSemester {
+    /// 학년도
+    year: u32,
+    /// 학기
+    semester: SemesterType,
+    /// 수강신청한 과목
+    lectures: Vec<RegisteredLecture>,
+}
+
+impl RegistrationSemester {
+    pub(crate) fn new(year: u32, semester: SemesterType, lectures: Vec<RegisteredLecture>) -> Self {
+        Self {
+            year,
+            semester,
+            lectures,
+        }
+    }
+
+    /// 학년도
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 학기
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 수강신청한 과목
+    pub fn lectures(&self) -> &[RegisteredLecture] {
+        &self.lectures
+    }
+}
+
+/// 입학 이후 전체 학기의 수강신청 내역
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct RegistrationHistory {
+    /// 수강신청 내역이 있는 학기별 내역
+    semesters: Vec<RegistrationSemester>,
+}
+
+impl RegistrationHistory {
+    pub(crate) fn new(semesters: Vec<RegistrationSemester>) -> Self {
+        Self { semesters }
+    }
+
+    /// 수강신청 내역이 있는 학기별 내역
+    pub fn semesters(&self) -> &[RegistrationSemester] {
+        &self.semesters
+    }
+
+    /// 전체 학기의 수강신청 과목을 학기 순서대로 반환합니다.
+    pub fn lectures(&self) -> impl Iterator<Item = &RegisteredLecture> {
+        self.semesters
+            .iter()
+            .flat_map(|semester| semester.lectures.iter())
+    }
+
+    /// 전체 학기의 수강신청 과목 수
+    pub fn total_lectures(&self) -> usize {
+        self.semesters
+            .iter()
+            .map(|semester| semester.lectures.len())
+            .sum()
+    }
 }
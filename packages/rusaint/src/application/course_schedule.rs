@@ -12,7 +12,7 @@ use crate::client::{USaintApplication, USaintClient};
 use crate::{
     ApplicationError, RusaintError,
     application::course_schedule::model::{Lecture, LectureCategory},
-    model::SemesterType,
+    model::{LectureCode, SemesterType},
 };
 use wdpe::command::WebDynproCommandExecutor;
 use wdpe::element::definition::ElementDefinition as _;
@@ -135,7 +135,7 @@ impl<'app> CourseScheduleApplication {
         row: &SapTableRow,
         code_col_idx: usize,
         parser: &ElementParser,
-        code: &str,
+        code: &LectureCode,
     ) -> bool {
         let Ok(code_cell) = SapTableCellWrapper::from_def(&row[code_col_idx], parser) else {
             return false;
@@ -143,11 +143,11 @@ impl<'app> CourseScheduleApplication {
         match code_cell.content() {
             Some(ElementDefWrapper::Link(link_def)) => parser
                 .element_from_def(&link_def)
-                .map(|link| link.text() == code)
+                .map(|link| link.text().trim() == code.as_str())
                 .unwrap_or(false),
             Some(ElementDefWrapper::TextView(tv_def)) => parser
                 .element_from_def(&tv_def)
-                .map(|tv| tv.text() == code)
+                .map(|tv| tv.text().trim() == code.as_str())
                 .unwrap_or(false),
             _ => false,
         }
@@ -155,6 +155,7 @@ impl<'app> CourseScheduleApplication {
 
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
+    /// 현재 학기는 [`current_semester`](crate::application::current_semester::current_semester)로 확인하세요.
     pub fn get_selected_semester(&self) -> Result<(u32, SemesterType), RusaintError> {
         Ok(get_selected_semester(
             &self.client,
@@ -518,7 +519,10 @@ impl<'app> CourseScheduleApplication {
 
     /// 주어진 과목번호에 해당하는 강의의 상세 정보를 가져옵니다.
     /// `find_lectures` 함수를 먼저 호출하여 강의를 검색한 이후에 사용되어야 합니다.
-    pub async fn lecture_detail(&mut self, code: &str) -> Result<LectureDetail, RusaintError> {
+    pub async fn lecture_detail(
+        &mut self,
+        code: &LectureCode,
+    ) -> Result<LectureDetail, RusaintError> {
         let parser = ElementParser::new(self.body());
         let table = parser.read(SapTableBodyCommand::new(Self::MAIN_TABLE))?;
 
@@ -557,12 +561,15 @@ impl<'app> CourseScheduleApplication {
     /// 주어진 과목번호에 해당하는 강의의 강의계획서(syllabus) 데이터를 OZ 서버에서 가져옵니다.
     /// `find_lectures` 함수를 먼저 호출하여 강의를 검색한 이후에 사용되어야 합니다.
     /// 강의계획서가 없는 강의의 경우 에러를 반환합니다.
-    pub async fn lecture_syllabus(&mut self, code: &str) -> Result<LectureSyllabus, RusaintError> {
+    pub async fn lecture_syllabus(
+        &mut self,
+        code: &LectureCode,
+    ) -> Result<LectureSyllabus, RusaintError> {
         let activate_event = self.find_syllabus_activate_event(code)?;
         self.process_syllabus_event(activate_event).await
     }
 
-    fn find_syllabus_activate_event(&self, code: &str) -> Result<Event, RusaintError> {
+    fn find_syllabus_activate_event(&self, code: &LectureCode) -> Result<Event, RusaintError> {
         let parser = ElementParser::new(self.body());
         let table = parser.read(SapTableBodyCommand::new(Self::MAIN_TABLE))?;
 
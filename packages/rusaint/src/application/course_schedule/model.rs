@@ -11,6 +11,7 @@ use crate::{
         request, request_lv1, request_lv2, request_lv3, request_text,
     },
     client::USaintClient,
+    model::{LectureCode, LectureSection},
 };
 use wdpe::element::parser::ElementParser;
 use wdpe::{
@@ -407,7 +408,7 @@ impl LectureCategory {
 
 /// 과목 정보
 #[allow(unused)]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct Lecture {
     /// 계획
@@ -443,7 +444,7 @@ pub struct Lecture {
     pub field: Option<String>,
     /// 과목번호
     #[serde(rename(deserialize = "과목번호"))]
-    pub code: String,
+    pub code: LectureCode,
     /// 과목명
     #[serde(rename(deserialize = "과목명"))]
     pub name: String,
@@ -477,6 +478,13 @@ pub struct Lecture {
     pub target: String,
 }
 
+impl Lecture {
+    /// 과목번호와 분반
+    pub fn section(&self) -> LectureSection {
+        LectureSection::new(self.code.clone(), self.division.clone())
+    }
+}
+
 impl<'body> FromSapTable<'body> for Lecture {
     fn from_table(
         header: Option<&'body wdpe::element::complex::sap_table::SapTableHeader>,
@@ -15,6 +15,7 @@ use wdpe::{
 };
 
 use crate::application::utils::sap_table::is_sap_table_empty;
+use crate::model::LectureCode;
 
 /// 강의 변경 이력
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -57,7 +58,7 @@ pub struct AlternativeLecture {
     pub kind: String,
     /// 과목번호
     #[serde(rename(deserialize = "과목번호"))]
-    pub code: String,
+    pub code: LectureCode,
     /// 과목명
     #[serde(rename(deserialize = "과목명"))]
     pub name: String,
@@ -86,7 +87,7 @@ impl<'body> FromSapTable<'body> for AlternativeLecture {
 pub struct PrerequisiteLecture {
     /// 과목번호
     #[serde(rename(deserialize = "과목번호"))]
-    pub code: String,
+    pub code: LectureCode,
     /// 과목명
     #[serde(rename(deserialize = "과목명"))]
     pub name: String,
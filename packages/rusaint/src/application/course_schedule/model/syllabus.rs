@@ -4,6 +4,7 @@ use ozra::types::{DataSet, FieldValue};
 
 use crate::ApplicationError;
 use crate::RusaintError;
+use crate::model::LectureCode;
 
 /// 강의계획서 - Full lecture syllabus information
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,7 +15,7 @@ pub struct LectureSyllabus {
     /// 담당교수
     pub professor: String,
     /// 과목코드
-    pub course_code: String,
+    pub course_code: LectureCode,
     /// 학년도
     pub year: String,
     /// 학기
@@ -120,7 +121,7 @@ impl LectureSyllabus {
 
         let course_name = get_string_field(plan, "SMTEXT");
         let professor = get_string_field(plan, "PROF_NM");
-        let course_code = get_string_field(plan, "SMOBJID");
+        let course_code = LectureCode::new(get_string_field(plan, "SMOBJID"));
         let year = get_string_field(plan, "PERYR");
         let semester = get_string_field(plan, "PERID");
         let credits = get_string_field(plan, "PTPLAN");
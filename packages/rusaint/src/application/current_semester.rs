@@ -0,0 +1,99 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::application::course_schedule::CourseScheduleApplication;
+use crate::application::utils::semester::{semester_on, today_kst};
+use crate::client::USaintClientBuilder;
+use crate::model::SemesterType;
+
+/// 현재 학기를 판별한 방법
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum CurrentSemesterSource {
+    /// u-saint 강의시간표 애플리케이션의 기본 선택값
+    USaint,
+    /// 날짜 범위로 추정한 값
+    Date,
+}
+
+/// 현재 학년도와 학기
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct CurrentSemester {
+    /// 학년도
+    year: u32,
+    /// 학기
+    semester: SemesterType,
+    /// 판별 방법
+    source: CurrentSemesterSource,
+    /// u-saint에서 학기를 가져오지 못해 날짜로 추정한 경우 그 이유
+    fallback_reason: Option<String>,
+}
+
+impl CurrentSemester {
+    /// 주어진 날짜가 속한 학기를 날짜 범위로 추정합니다.
+    ///
+    /// 3월 1일 ~ 6월 20일은 1학기, 6월 21일 ~ 8월 31일은 여름학기, 9월 1일 ~ 12월 20일은 2학기,
+    /// 12월 21일 ~ 다음 해 2월 말일은 (12월이 속한 학년도의) 겨울학기로 간주합니다.
+    pub fn on(date: NaiveDate) -> Self {
+        let (year, semester) = semester_on(date);
+        Self {
+            year,
+            semester,
+            source: CurrentSemesterSource::Date,
+            fallback_reason: None,
+        }
+    }
+
+    /// 한국 표준시(UTC+9) 기준 오늘 날짜가 속한 학기를 [`CurrentSemester::on`]과 같이 추정합니다.
+    pub fn today() -> Self {
+        Self::on(today_kst())
+    }
+
+    /// 학년도
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 학기
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 판별 방법
+    pub fn source(&self) -> CurrentSemesterSource {
+        self.source
+    }
+
+    /// u-saint에서 학기를 가져오지 못해 날짜로 추정한 경우 그 이유
+    pub fn fallback_reason(&self) -> Option<&str> {
+        self.fallback_reason.as_deref()
+    }
+}
+
+/// 현재 학년도와 학기를 판별합니다.
+///
+/// 새로 불러온 강의시간표 애플리케이션의 기본 선택 학기를 현재 학기로 사용합니다.
+/// 애플리케이션을 불러오지 못하거나 선택된 학기를 읽을 수 없다면 [`CurrentSemester::today`]와 같이 한국 표준시 기준 오늘 날짜로 추정합니다.
+/// 강의시간표는 로그인 없이도 조회할 수 있으므로 세션이 없는 빌더를 사용해도 됩니다.
+pub async fn current_semester(builder: USaintClientBuilder) -> CurrentSemester {
+    let selected = match builder.build_into::<CourseScheduleApplication>().await {
+        Ok(app) => app.get_selected_semester(),
+        Err(err) => Err(err),
+    };
+    match selected {
+        Ok((year, semester)) => CurrentSemester {
+            year,
+            semester,
+            source: CurrentSemesterSource::USaint,
+            fallback_reason: None,
+        },
+        Err(err) => {
+            tracing::debug!("Falling back to date-based semester: {}", err);
+            CurrentSemester {
+                fallback_reason: Some(err.to_string()),
+                ..CurrentSemester::today()
+            }
+        }
+    }
+}
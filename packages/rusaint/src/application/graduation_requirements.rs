@@ -171,6 +171,8 @@ impl<'a> GraduationRequirementsApplication {
 
 /// 졸업사정표 애플리케이션에서 사용되는 데이터의 모듈
 pub mod model;
+/// 충족되지 않은 졸업 요건을 개설 강의와 연결하는 수강 계획 도구
+pub mod planner;
 
 #[cfg(test)]
 mod test {
@@ -131,6 +131,7 @@ impl GraduationStudent {
 pub struct GraduationRequirements {
     is_graduatable: bool,
     requirements: HashMap<String, GraduationRequirement>,
+    progress: GraduationProgress,
 }
 
 impl GraduationRequirements {
@@ -138,9 +139,11 @@ impl GraduationRequirements {
         is_graduatable: bool,
         requirements: HashMap<String, GraduationRequirement>,
     ) -> Self {
+        let progress = GraduationProgress::from_requirements(requirements.values());
         Self {
             is_graduatable,
             requirements,
+            progress,
         }
     }
 
@@ -153,6 +156,22 @@ impl GraduationRequirements {
     pub fn requirements(&self) -> &HashMap<String, GraduationRequirement> {
         &self.requirements
     }
+
+    /// 전체 졸업 요건의 진행 상황
+    pub fn progress(&self) -> &GraduationProgress {
+        &self.progress
+    }
+
+    /// 주어진 분류에 해당하는 졸업요건
+    pub fn by_kind(&self, kind: RequirementKind) -> Vec<&GraduationRequirement> {
+        let mut requirements: Vec<&GraduationRequirement> = self
+            .requirements
+            .values()
+            .filter(|requirement| requirement.kind() == kind)
+            .collect();
+        requirements.sort_by(|a, b| a.name().cmp(b.name()));
+        requirements
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -164,11 +183,13 @@ pub struct GraduationRequirement {
         deserialize_with = "deserialize_with_trim"
     )]
     name: String,
+    #[serde(skip_deserializing)]
+    requirement: Option<u32>,
     #[serde(
         rename(deserialize = "기준값"),
-        deserialize_with = "deserialize_option_u32_string"
+        deserialize_with = "deserialize_option_f32_string"
     )]
-    requirement: Option<u32>,
+    requirement_value: Option<f32>,
     #[serde(
         rename(deserialize = "계산값"),
         deserialize_with = "deserialize_option_f32_string"
@@ -191,6 +212,12 @@ pub struct GraduationRequirement {
         deserialize_with = "deserialize_lectures"
     )]
     lectures: Vec<String>,
+    #[serde(skip_deserializing)]
+    kind: RequirementKind,
+    #[serde(skip_deserializing)]
+    unit: RequirementUnit,
+    #[serde(skip_deserializing)]
+    percent_complete: f32,
 }
 
 impl GraduationRequirement {
@@ -199,11 +226,16 @@ impl GraduationRequirement {
         &self.name
     }
 
-    /// 기준값
+    /// 기준값 (정수가 아니라면 `None`이며, [`requirement_value`](Self::requirement_value)로 확인할 수 있습니다)
     pub fn requirement(&self) -> Option<u32> {
         self.requirement
     }
 
+    /// 기준값을 실수로 나타낸 값
+    pub fn requirement_value(&self) -> Option<f32> {
+        self.requirement_value
+    }
+
     /// 계산값
     pub fn calculation(&self) -> Option<f32> {
         self.calculation
@@ -228,20 +260,37 @@ impl GraduationRequirement {
     pub fn lectures(&self) -> &[String] {
         &self.lectures
     }
-}
 
-pub(crate) fn deserialize_option_u32_string<'de, D: Deserializer<'de>>(
-    deserializer: D,
-) -> Result<Option<u32>, D::Error> {
-    let value = String::deserialize(deserializer)?;
-    if value.trim().is_empty() {
-        return Ok(None);
+    /// 요건 분류
+    pub fn kind(&self) -> RequirementKind {
+        self.kind
+    }
+
+    /// 기준값의 단위
+    pub fn unit(&self) -> RequirementUnit {
+        self.unit
+    }
+
+    /// 달성률 (0 ~ 100)
+    pub fn percent_complete(&self) -> f32 {
+        self.percent_complete
+    }
+
+    fn classified(mut self) -> Self {
+        self.requirement = self
+            .requirement_value
+            .filter(|value| *value >= 0.0 && value.fract() == 0.0)
+            .map(|value| value as u32);
+        self.kind = RequirementKind::classify(&self.name, &self.category);
+        self.unit = RequirementUnit::classify(self.kind, &self.name, self.requirement_value);
+        self.percent_complete = percent_complete(
+            self.unit,
+            self.requirement_value,
+            self.calculation,
+            self.result,
+        );
+        self
     }
-    value
-        .trim()
-        .parse()
-        .map(Some)
-        .map_err(serde::de::Error::custom)
 }
 
 pub(crate) fn deserialize_option_f32_string<'de, D: Deserializer<'de>>(
@@ -269,6 +318,8 @@ fn deserialize_lectures<'de, D: Deserializer<'de>>(
     let value = String::deserialize(deserializer)?;
     Ok(value
         .split(", ")
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
         .map(str::to_owned)
         .collect::<Vec<String>>())
 }
@@ -281,11 +332,62 @@ impl<'body> FromSapTable<'body> for GraduationRequirement {
     ) -> Result<Self, wdpe::error::WebDynproError> {
         let map_string = row.try_row_into::<HashMap<String, String>>(header, parser)?;
         let map_de: MapDeserializer<_, serde::de::value::Error> = map_string.into_deserializer();
-        Ok(GraduationRequirement::deserialize(map_de).map_err(|e| {
-            ElementError::InvalidContent {
+        Ok(GraduationRequirement::deserialize(map_de)
+            .map_err(|e| ElementError::InvalidContent {
                 element: row.table_def().id().to_string(),
                 content: e.to_string(),
-            }
-        })?)
+            })?
+            .classified())
+    }
+}
+
+mod lecture_link;
+mod progress;
+
+pub use lecture_link::RequirementLecture;
+use progress::percent_complete;
+pub use progress::{GraduationProgress, RequirementKind, RequirementUnit};
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::de::{IntoDeserializer, value::MapDeserializer};
+
+    use super::*;
+
+    fn requirement(name: &str, requirement: &str, calculation: &str) -> GraduationRequirement {
+        let map: HashMap<String, String> = [
+            ("졸업요건", name),
+            ("기준값", requirement),
+            ("계산값", calculation),
+            ("계산값 - 기준값", ""),
+            ("결과", "불충족"),
+            ("이수구분", ""),
+            ("과목사용", ""),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map.into_deserializer();
+        GraduationRequirement::deserialize(map_de)
+            .unwrap()
+            .classified()
+    }
+
+    #[test]
+    fn reads_fractional_requirement() {
+        let gpa = requirement("학부-평점평균", "2.0", "1.5");
+        assert_eq!(gpa.requirement_value(), Some(2.0));
+        assert_eq!(gpa.requirement(), Some(2));
+        assert_eq!(gpa.unit(), RequirementUnit::Gpa);
+        assert_eq!(gpa.percent_complete(), 75.0);
+        let fractional = requirement("학부-평점평균", "1.75", "");
+        assert_eq!(fractional.requirement_value(), Some(1.75));
+        assert_eq!(fractional.requirement(), None);
+        assert_eq!(
+            requirement("학부-졸업학점", "133", "").requirement(),
+            Some(133)
+        );
     }
 }
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{GraduationRequirement, GraduationRequirements};
+use crate::application::course_grades::model::{ClassGradeItem, GradesByClassification};
+use crate::model::LectureCode;
+
+/// 졸업 요건에 사용된 과목과 해당 과목의 성적
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct RequirementLecture {
+    /// 졸업사정표에 표시된 과목 이름
+    name: String,
+    /// 이수구분별 성적에서 찾은 과목 성적 (찾지 못했다면 `None`)
+    grade: Option<ClassGradeItem>,
+}
+
+impl RequirementLecture {
+    /// 졸업사정표에 표시된 과목 이름
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 이수구분별 성적에서 찾은 과목 성적
+    pub fn grade(&self) -> Option<&ClassGradeItem> {
+        self.grade.as_ref()
+    }
+
+    /// 과목코드
+    pub fn code(&self) -> Option<&LectureCode> {
+        self.grade.as_ref().map(ClassGradeItem::course_code)
+    }
+
+    /// 학점
+    pub fn credits(&self) -> Option<f32> {
+        self.grade.as_ref().and_then(ClassGradeItem::credits)
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// 과목 이름에 붙은 학점 등의 괄호 표기를 제거합니다. (e.g. "데이터베이스(3)" -> "데이터베이스")
+fn strip_suffix(name: &str) -> &str {
+    match name.trim_end().strip_suffix(')') {
+        Some(rest) => rest.rsplit_once('(').map_or(name, |(name, _)| name),
+        None => name,
+    }
+}
+
+fn find_grade<'a>(entry: &str, grades: &'a [ClassGradeItem]) -> Option<&'a ClassGradeItem> {
+    let entry = entry.trim();
+    let normalized = normalize(entry);
+    let stripped = normalize(strip_suffix(entry));
+    let candidates: Vec<&ClassGradeItem> = grades
+        .iter()
+        .filter(|grade| {
+            let name = normalize(grade.course_name());
+            let code = grade.course_code().as_str();
+            name == normalized || name == stripped || (!code.is_empty() && entry.contains(code))
+        })
+        .collect();
+    // 재수강 등으로 여러 기록이 있다면 학점을 취득한 가장 마지막 기록을 사용합니다.
+    candidates
+        .iter()
+        .rev()
+        .find(|grade| grade.grade().is_earned())
+        .or(candidates.last())
+        .copied()
+}
+
+impl GraduationRequirement {
+    /// 졸업 요건에 사용된 과목을 이수구분별 성적과 연결합니다.
+    pub fn resolve_lectures(&self, grades: &GradesByClassification) -> Vec<RequirementLecture> {
+        self.lectures()
+            .iter()
+            .map(|name| RequirementLecture {
+                name: name.clone(),
+                grade: find_grade(name, grades.grades()).cloned(),
+            })
+            .collect()
+    }
+}
+
+impl GraduationRequirements {
+    /// 모든 졸업 요건에 사용된 과목을 이수구분별 성적과 연결합니다. 졸업요건 이름을 키로 사용합니다.
+    pub fn resolve_lectures(
+        &self,
+        grades: &GradesByClassification,
+    ) -> HashMap<String, Vec<RequirementLecture>> {
+        self.requirements()
+            .iter()
+            .map(|(name, requirement)| (name.clone(), requirement.resolve_lectures(grades)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ozra::types::FieldValue;
+
+    use crate::application::course_grades::model::LetterGrade;
+
+    use super::*;
+
+    #[test]
+    fn strips_suffix() {
+        assert_eq!(strip_suffix("데이터베이스(3)"), "데이터베이스");
+        assert_eq!(strip_suffix("데이터베이스"), "데이터베이스");
+        assert_eq!(normalize(" 대학 글쓰기 "), "대학글쓰기");
+    }
+
+    fn grades() -> GradesByClassification {
+        let field =
+            |name: &str, value: &str| (name.to_string(), FieldValue::String(value.to_string()));
+        let row = |year: &str, code: &str, name: &str, score: &str, grade: &str| {
+            vec![
+                field("PERYR", year),
+                field("SM_ID", code),
+                field("SM_TEXT", name),
+                field("CPATTEMP", "3.0"),
+                field("GRADESYMBOL", score),
+                field("GRADE", grade),
+            ]
+        };
+        let datasets = vec![
+            (
+                "Shadow_master".to_string(),
+                vec![vec![field("ST_NO", "20211561")]],
+            ),
+            (
+                "ITAB".to_string(),
+                vec![
+                    row("2021", "2150001", "자료구조", "72", "C0"),
+                    row("2022", "2150001", "자료구조", "95", "A+"),
+                    row("2021", "2150002", "선형대수", "40", "F"),
+                    row("2022", "2150002", "선형대수", "86", "B+"),
+                    row("2023", "2150002", "선형대수", "30", "F"),
+                    row("2022", "2150003", "컴퓨터구조및실습", "90", "A0"),
+                ],
+            ),
+        ];
+        GradesByClassification::from_datasets(&datasets).unwrap()
+    }
+
+    #[test]
+    fn prefers_latest_earned_attempt() {
+        let grades = grades();
+        let retaken = find_grade("자료구조(3)", grades.grades()).unwrap();
+        assert_eq!(retaken.year(), "2022");
+        assert_eq!(retaken.grade(), &LetterGrade::APlus);
+        let passed = find_grade("선형대수", grades.grades()).unwrap();
+        assert_eq!(passed.year(), "2022");
+        assert!(passed.grade().is_earned());
+    }
+
+    #[test]
+    fn matches_by_course_code() {
+        let grades = grades();
+        let grade = find_grade("컴퓨터 구조 (2150003)", grades.grades()).unwrap();
+        assert_eq!(grade.course_name(), "컴퓨터구조및실습");
+        assert!(find_grade("운영체제", grades.grades()).is_none());
+    }
+}
@@ -0,0 +1,249 @@
+use serde::{Deserialize, Serialize};
+
+use super::GraduationRequirement;
+
+/// 졸업 요건의 분류
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum RequirementKind {
+    /// 졸업학점(총 이수학점)
+    TotalCredits,
+    /// 주전공 학점
+    MajorCredits,
+    /// 복수전공, 부전공, 연계전공 등 다전공 학점
+    SecondaryMajorCredits,
+    /// 교양필수
+    LiberalArtsRequired,
+    /// 교양선택 및 교양 영역
+    LiberalArtsElective,
+    /// 평점평균
+    Gpa,
+    /// 영어 등 외국어 인증
+    English,
+    /// 졸업 인증, 자격 등 기타 인증
+    Certification,
+    /// 채플
+    Chapel,
+    /// 분류할 수 없는 요건
+    #[default]
+    Other,
+}
+
+impl RequirementKind {
+    /// 졸업요건 이름과 이수구분으로 분류를 추정합니다.
+    pub(crate) fn classify(name: &str, category: &str) -> RequirementKind {
+        let text: String = format!("{name} {category}").split_whitespace().collect();
+        let contains_any = |keywords: &[&str]| keywords.iter().any(|k| text.contains(k));
+        if contains_any(&["채플"]) {
+            RequirementKind::Chapel
+        } else if contains_any(&["평점", "평균", "GPA"]) {
+            RequirementKind::Gpa
+        } else if contains_any(&["영어", "외국어", "TOEIC", "토익", "어학"]) {
+            RequirementKind::English
+        } else if contains_any(&["인증", "자격", "논문", "시험"]) {
+            RequirementKind::Certification
+        } else if contains_any(&[
+            "복수전공",
+            "부전공",
+            "연계전공",
+            "융합전공",
+            "복필",
+            "복선",
+            "부필",
+            "부선",
+        ]) {
+            RequirementKind::SecondaryMajorCredits
+        } else if contains_any(&["전공", "전필", "전선", "전기"]) {
+            RequirementKind::MajorCredits
+        } else if contains_any(&["교양필수", "교필"]) {
+            RequirementKind::LiberalArtsRequired
+        } else if contains_any(&["교양", "교선"]) {
+            RequirementKind::LiberalArtsElective
+        } else if contains_any(&["졸업학점", "총학점", "총이수", "학점"]) {
+            RequirementKind::TotalCredits
+        } else {
+            RequirementKind::Other
+        }
+    }
+}
+
+/// 졸업 요건 값의 단위
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum RequirementUnit {
+    /// 학점
+    #[default]
+    Credits,
+    /// 횟수 또는 과목 수
+    Count,
+    /// 평점
+    Gpa,
+    /// 충족 여부만 있는 요건
+    PassFail,
+}
+
+impl RequirementUnit {
+    /// 졸업 요건의 분류와 이름, 기준값으로 단위를 추정합니다.
+    pub(crate) fn classify(
+        kind: RequirementKind,
+        name: &str,
+        requirement: Option<f32>,
+    ) -> RequirementUnit {
+        if requirement.is_none() {
+            return RequirementUnit::PassFail;
+        }
+        match kind {
+            RequirementKind::Gpa => RequirementUnit::Gpa,
+            RequirementKind::English | RequirementKind::Certification => RequirementUnit::PassFail,
+            RequirementKind::Chapel => RequirementUnit::Count,
+            _ if name.contains("학점") => RequirementUnit::Credits,
+            _ if name.contains("횟수") || name.contains("과목수") || name.ends_with('회') => {
+                RequirementUnit::Count
+            }
+            _ => RequirementUnit::Credits,
+        }
+    }
+}
+
+/// 졸업 요건의 달성률을 0 ~ 100 사이의 백분율로 계산합니다.
+pub(crate) fn percent_complete(
+    unit: RequirementUnit,
+    requirement: Option<f32>,
+    calculation: Option<f32>,
+    result: bool,
+) -> f32 {
+    if result {
+        return 100.0;
+    }
+    match (unit, requirement, calculation) {
+        (RequirementUnit::PassFail, _, _) => 0.0,
+        (_, Some(requirement), Some(calculation)) if requirement > 0.0 => {
+            (calculation / requirement * 100.0).clamp(0.0, 100.0)
+        }
+        _ => 0.0,
+    }
+}
+
+/// 전체 졸업 요건의 진행 상황
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct GraduationProgress {
+    /// 전체 요건 수
+    total: u32,
+    /// 충족한 요건 수
+    satisfied: u32,
+    /// 요건별 달성률의 평균 (0 ~ 100)
+    percent_complete: f32,
+    /// 졸업학점 기준값
+    required_credits: Option<f32>,
+    /// 졸업학점 계산값
+    completed_credits: Option<f32>,
+}
+
+impl GraduationProgress {
+    pub(crate) fn from_requirements<'a>(
+        requirements: impl IntoIterator<Item = &'a GraduationRequirement>,
+    ) -> GraduationProgress {
+        let mut progress = GraduationProgress::default();
+        let mut percent_sum = 0.0;
+        for requirement in requirements {
+            progress.total += 1;
+            if requirement.result() {
+                progress.satisfied += 1;
+            }
+            percent_sum += requirement.percent_complete();
+            if requirement.kind() == RequirementKind::TotalCredits
+                && requirement.unit() == RequirementUnit::Credits
+                && progress.required_credits.is_none()
+            {
+                progress.required_credits = requirement.requirement_value();
+                progress.completed_credits = requirement.calculation();
+            }
+        }
+        if progress.total > 0 {
+            progress.percent_complete = percent_sum / progress.total as f32;
+        }
+        progress
+    }
+
+    /// 전체 요건 수
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+
+    /// 충족한 요건 수
+    pub fn satisfied(&self) -> u32 {
+        self.satisfied
+    }
+
+    /// 충족하지 못한 요건 수
+    pub fn unsatisfied(&self) -> u32 {
+        self.total - self.satisfied
+    }
+
+    /// 요건별 달성률의 평균 (0 ~ 100)
+    pub fn percent_complete(&self) -> f32 {
+        self.percent_complete
+    }
+
+    /// 졸업학점 기준값
+    pub fn required_credits(&self) -> Option<f32> {
+        self.required_credits
+    }
+
+    /// 졸업학점 계산값
+    pub fn completed_credits(&self) -> Option<f32> {
+        self.completed_credits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_requirements() {
+        assert_eq!(
+            RequirementKind::classify("학부-졸업학점", ""),
+            RequirementKind::TotalCredits
+        );
+        assert_eq!(
+            RequirementKind::classify("학부-주전공(전필)", "전필"),
+            RequirementKind::MajorCredits
+        );
+        assert_eq!(
+            RequirementKind::classify("학부-교양필수", "교필"),
+            RequirementKind::LiberalArtsRequired
+        );
+        assert_eq!(
+            RequirementKind::classify("학부-채플", ""),
+            RequirementKind::Chapel
+        );
+        assert_eq!(
+            RequirementKind::classify("학부-평점평균", ""),
+            RequirementKind::Gpa
+        );
+        assert_eq!(
+            RequirementUnit::classify(RequirementKind::Gpa, "학부-평점평균", Some(2.0)),
+            RequirementUnit::Gpa
+        );
+        assert_eq!(
+            RequirementUnit::classify(RequirementKind::English, "영어인증", None),
+            RequirementUnit::PassFail
+        );
+    }
+
+    #[test]
+    fn computes_percent_complete() {
+        let percent = percent_complete(RequirementUnit::Credits, Some(133.0), Some(66.5), false);
+        assert_eq!(percent, 50.0);
+        assert_eq!(
+            percent_complete(RequirementUnit::Credits, Some(10.0), Some(20.0), true),
+            100.0
+        );
+        assert_eq!(
+            percent_complete(RequirementUnit::PassFail, None, None, false),
+            0.0
+        );
+    }
+}
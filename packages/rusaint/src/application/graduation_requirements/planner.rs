@@ -0,0 +1,400 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    GraduationRequirementsApplication,
+    model::{GraduationRequirement, GraduationRequirements, RequirementUnit},
+};
+use crate::{
+    RusaintError,
+    application::{
+        course_grades::{CourseGradesApplication, model::CourseType},
+        course_schedule::{
+            CourseScheduleApplication,
+            model::{Lecture, LectureCategory},
+        },
+    },
+    model::{LectureCode, SemesterType},
+};
+
+/// 충족되지 않은 졸업 요건을 채우기 위한 수강 계획
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct GraduationPlan {
+    /// 강의를 검색한 학년도
+    year: u32,
+    /// 강의를 검색한 학기
+    semester: SemesterType,
+    /// 충족되지 않은 졸업 요건별 계획
+    requirements: Vec<RequirementPlan>,
+}
+
+impl GraduationPlan {
+    /// 졸업 요건과 개설 강의, 이미 이수한 과목으로 수강 계획을 만듭니다.
+    ///
+    /// 충족되지 않은 졸업 요건마다 이수구분(또는 교과영역)이 일치하는 강의를 후보로 찾고,
+    /// 이미 이수했거나 졸업 요건에 사용된 과목을 제외한 뒤 부족한 학점(또는 과목 수)을 채울 때까지 강의를 제안합니다.
+    /// 평점평균 요건은 강의를 추가로 수강하여 채울 수 없으므로 제외합니다.
+    pub fn new(
+        year: u32,
+        semester: SemesterType,
+        requirements: &GraduationRequirements,
+        lectures: &[Lecture],
+        taken_codes: &HashSet<LectureCode>,
+    ) -> GraduationPlan {
+        let mut unmet: Vec<&GraduationRequirement> = requirements
+            .requirements()
+            .values()
+            .filter(|requirement| !requirement.result())
+            .filter(|requirement| requirement.unit() != RequirementUnit::Gpa)
+            .collect();
+        unmet.sort_by(|a, b| a.name().cmp(b.name()));
+        let requirements = unmet
+            .into_iter()
+            .map(|requirement| RequirementPlan::new(requirement, lectures, taken_codes))
+            .collect();
+        GraduationPlan {
+            year,
+            semester,
+            requirements,
+        }
+    }
+
+    /// 강의를 검색한 학년도
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 강의를 검색한 학기
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 충족되지 않은 졸업 요건별 계획
+    pub fn requirements(&self) -> &[RequirementPlan] {
+        &self.requirements
+    }
+}
+
+/// 졸업 요건 하나에 대한 수강 계획
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct RequirementPlan {
+    /// 졸업요건
+    name: String,
+    /// 이수구분
+    category: String,
+    /// 기준값의 단위
+    unit: RequirementUnit,
+    /// 부족한 값 (기준값 - 계산값)
+    shortfall: Option<f32>,
+    /// 조건에 맞는 전체 강의
+    candidates: Vec<PlannedLecture>,
+    /// 부족한 학점을 채우기 위해 제안하는 강의
+    proposed: Vec<PlannedLecture>,
+    /// 제안한 강의의 학점 합계
+    proposed_credits: f32,
+    /// 제안한 강의로 부족한 값을 채울 수 있는지 여부
+    closes_gap: bool,
+}
+
+impl RequirementPlan {
+    fn new(
+        requirement: &GraduationRequirement,
+        lectures: &[Lecture],
+        taken_codes: &HashSet<LectureCode>,
+    ) -> RequirementPlan {
+        let unit = requirement.unit();
+        let shortfall = shortfall(unit, requirement.difference());
+        let mut seen_names = HashSet::new();
+        let candidates: Vec<PlannedLecture> = lectures
+            .iter()
+            .filter(|lecture| matches_requirement(requirement, lecture))
+            .filter(|lecture| !is_taken(&lecture.code, taken_codes))
+            .filter(|lecture| !requirement.lectures().contains(&lecture.name))
+            .filter(|lecture| seen_names.insert(lecture.name.clone()))
+            .map(PlannedLecture::from_lecture)
+            .collect();
+        let (len, filled) = proposal_len(
+            unit,
+            shortfall,
+            candidates.iter().map(|candidate| candidate.credits),
+        );
+        let proposed = candidates[..len].to_vec();
+        let proposed_credits = proposed
+            .iter()
+            .map(|candidate| candidate.credits.unwrap_or(0.0))
+            .sum();
+        let closes_gap = match shortfall {
+            Some(shortfall) => filled >= shortfall,
+            None => !proposed.is_empty(),
+        };
+        RequirementPlan {
+            name: requirement.name().to_string(),
+            category: requirement.category().to_string(),
+            unit,
+            shortfall,
+            candidates,
+            proposed,
+            proposed_credits,
+            closes_gap,
+        }
+    }
+
+    /// 졸업요건
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 이수구분
+    pub fn category(&self) -> &str {
+        &self.category
+    }
+
+    /// 기준값의 단위
+    pub fn unit(&self) -> RequirementUnit {
+        self.unit
+    }
+
+    /// 부족한 값 (기준값 - 계산값)
+    ///
+    /// 학점과 과목 수 요건에만 존재하며, 충족 여부만 있는 요건은 `None`입니다.
+    pub fn shortfall(&self) -> Option<f32> {
+        self.shortfall
+    }
+
+    /// 조건에 맞는 전체 강의
+    pub fn candidates(&self) -> &[PlannedLecture] {
+        &self.candidates
+    }
+
+    /// 부족한 학점을 채우기 위해 제안하는 강의
+    pub fn proposed(&self) -> &[PlannedLecture] {
+        &self.proposed
+    }
+
+    /// 제안한 강의의 학점 합계
+    pub fn proposed_credits(&self) -> f32 {
+        self.proposed_credits
+    }
+
+    /// 제안한 강의로 부족한 값을 채울 수 있는지 여부
+    pub fn closes_gap(&self) -> bool {
+        self.closes_gap
+    }
+}
+
+/// 수강 계획에 포함된 강의
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct PlannedLecture {
+    /// 강의 정보
+    lecture: Lecture,
+    /// 학점
+    credits: Option<f32>,
+}
+
+impl PlannedLecture {
+    fn from_lecture(lecture: &Lecture) -> PlannedLecture {
+        PlannedLecture {
+            credits: parse_credits(&lecture.time_points),
+            lecture: lecture.clone(),
+        }
+    }
+
+    /// 강의 정보
+    pub fn lecture(&self) -> &Lecture {
+        &self.lecture
+    }
+
+    /// 학점
+    pub fn credits(&self) -> Option<f32> {
+        self.credits
+    }
+}
+
+/// 이수구분 이름을 강의시간표에서 사용하는 약칭으로 변환합니다.
+fn normalize_category(category: &str) -> String {
+    let category: String = category.split_whitespace().collect();
+    match category.as_str() {
+        "전공필수" => "전필".to_string(),
+        "전공선택" => "전선".to_string(),
+        "전공기초" => "전기".to_string(),
+        "교양필수" => "교필".to_string(),
+        "교양선택" => "교선".to_string(),
+        "복수전공필수" => "복필".to_string(),
+        "복수전공선택" => "복선".to_string(),
+        "부전공필수" => "부필".to_string(),
+        "부전공선택" => "부선".to_string(),
+        _ => category,
+    }
+}
+
+fn matches_requirement(requirement: &GraduationRequirement, lecture: &Lecture) -> bool {
+    let category = normalize_category(requirement.category());
+    if category.is_empty() {
+        return false;
+    }
+    let lecture_categories = [Some(&lecture.category), lecture.sub_category.as_ref()];
+    let category_matches = lecture_categories
+        .into_iter()
+        .flatten()
+        .any(|lecture_category| normalize_category(lecture_category).starts_with(&category));
+    let field_matches = lecture
+        .field
+        .as_ref()
+        .is_some_and(|field| !field.is_empty() && requirement.name().contains(field.as_str()));
+    category_matches || field_matches
+}
+
+/// 과목번호가 이미 이수한 과목의 코드와 같은지 확인합니다.
+fn is_taken(code: &LectureCode, taken_codes: &HashSet<LectureCode>) -> bool {
+    taken_codes.contains(code)
+}
+
+/// 기준값의 단위에 따라 부족한 값을 계산합니다. 학점과 과목 수 요건이 아니라면 `None`입니다.
+fn shortfall(unit: RequirementUnit, difference: Option<f32>) -> Option<f32> {
+    match unit {
+        RequirementUnit::Credits | RequirementUnit::Count => {
+            difference.map(|difference| -difference)
+        }
+        RequirementUnit::Gpa | RequirementUnit::PassFail => None,
+    }
+}
+
+/// 부족한 값을 채울 때까지 제안할 후보의 수와 그 후보들로 채운 값을 계산합니다.
+///
+/// 학점 요건은 후보의 학점을, 과목 수 요건은 후보의 수를 더합니다. 부족한 값이 없다면 모든 후보를 제안합니다.
+fn proposal_len(
+    unit: RequirementUnit,
+    shortfall: Option<f32>,
+    credits: impl IntoIterator<Item = Option<f32>>,
+) -> (usize, f32) {
+    let mut len = 0;
+    let mut filled = 0.0;
+    for credits in credits {
+        if shortfall.is_some_and(|shortfall| filled >= shortfall) {
+            break;
+        }
+        filled += match unit {
+            RequirementUnit::Count => 1.0,
+            _ => credits.unwrap_or(0.0),
+        };
+        len += 1;
+    }
+    (len, filled)
+}
+
+/// "시간/학점(설계)" 값(e.g. "3.0/3.0 (0)")에서 학점을 가져옵니다.
+fn parse_credits(time_points: &str) -> Option<f32> {
+    time_points
+        .split('/')
+        .nth(1)?
+        .split(|c: char| c.is_whitespace() || c == '(')
+        .next()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// 졸업사정표, 강의시간표, 성적 조회 애플리케이션을 함께 사용하여 수강 계획을 만듭니다.
+///
+/// `queries`로 주어진 강의 분류를 주어진 학기에 검색하고, 이수구분별 성적에 포함된 과목을 이미 이수한 과목으로 보고 제외합니다.
+/// 졸업 요건의 이수구분과 강의시간표의 분류는 일대일로 대응하지 않으므로, 학과, 전공, 교양 영역 등 검색할 분류는 호출자가 지정해야 합니다.
+/// ### 예시
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::application::course_grades::{CourseGradesApplication, model::CourseType};
+/// # use rusaint::application::course_schedule::{CourseScheduleApplication, model::LectureCategory};
+/// # use rusaint::application::graduation_requirements::{GraduationRequirementsApplication, planner::plan_graduation};
+/// # use rusaint::client::USaintClientBuilder;
+/// # use rusaint::model::SemesterType;
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let mut requirements = USaintClientBuilder::new().session(session.clone()).build_into::<GraduationRequirementsApplication>().await.unwrap();
+/// let mut schedule = USaintClientBuilder::new().session(session.clone()).build_into::<CourseScheduleApplication>().await.unwrap();
+/// let mut grades = USaintClientBuilder::new().session(session).build_into::<CourseGradesApplication>().await.unwrap();
+/// let queries = [LectureCategory::major("IT대학", "글로벌미디어학부", None)];
+/// let plan = plan_graduation(&mut requirements, &mut schedule, &mut grades, CourseType::Bachelor, 2025, SemesterType::One, &queries).await.unwrap();
+/// for requirement in plan.requirements() {
+///     println!("{}: {:?}", requirement.name(), requirement.proposed());
+/// }
+/// # })
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub async fn plan_graduation(
+    requirements_app: &mut GraduationRequirementsApplication,
+    schedule_app: &mut CourseScheduleApplication,
+    grades_app: &mut CourseGradesApplication,
+    course_type: CourseType,
+    year: u32,
+    semester: SemesterType,
+    queries: &[LectureCategory],
+) -> Result<GraduationPlan, RusaintError> {
+    let requirements = requirements_app.requirements().await?;
+    let mut lectures = Vec::new();
+    for query in queries {
+        lectures.extend(schedule_app.find_lectures(year, semester, query).await?);
+    }
+    let taken_codes = grades_app
+        .grades_by_classification(course_type)
+        .await?
+        .grades()
+        .iter()
+        .map(|grade| grade.course_code().clone())
+        .collect();
+    Ok(GraduationPlan::new(
+        year,
+        semester,
+        &requirements,
+        &lectures,
+        &taken_codes,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_credits() {
+        assert_eq!(parse_credits("3.0/3.0"), Some(3.0));
+        assert_eq!(parse_credits("2.0/1.0 (0)"), Some(1.0));
+        assert_eq!(parse_credits(""), None);
+    }
+
+    #[test]
+    fn detects_taken_lectures() {
+        let taken = HashSet::from([LectureCode::from("21500123")]);
+        assert!(is_taken(&"21500123".into(), &taken));
+        assert!(!is_taken(&"2150012301".into(), &taken));
+        assert!(!is_taken(&"21500124".into(), &taken));
+    }
+
+    #[test]
+    fn computes_shortfall_by_unit() {
+        assert_eq!(shortfall(RequirementUnit::Credits, Some(-6.0)), Some(6.0));
+        assert_eq!(shortfall(RequirementUnit::Count, Some(-2.0)), Some(2.0));
+        assert_eq!(shortfall(RequirementUnit::Gpa, Some(-0.3)), None);
+        assert_eq!(shortfall(RequirementUnit::PassFail, None), None);
+
+        let credits = [Some(3.0), Some(3.0), Some(2.0)];
+        assert_eq!(
+            proposal_len(RequirementUnit::Credits, Some(5.0), credits),
+            (2, 6.0)
+        );
+        assert_eq!(
+            proposal_len(RequirementUnit::Count, Some(1.0), credits),
+            (1, 1.0)
+        );
+        assert_eq!(proposal_len(RequirementUnit::PassFail, None, credits).0, 3);
+    }
+
+    #[test]
+    fn normalizes_categories() {
+        assert_eq!(normalize_category("전공 필수"), "전필");
+        assert_eq!(normalize_category("교선"), "교선");
+    }
+}
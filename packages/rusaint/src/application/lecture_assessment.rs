@@ -1,32 +1,51 @@
-use model::LectureAssessmentResult;
+use model::{
+    AssessmentAggregation, AssessmentQuestionResult, LectureAssessmentDetail,
+    LectureAssessmentResult,
+};
+use ozra::types::DataSet;
+
+use crate::application::current_semester::CurrentSemester;
+use crate::application::utils::oz::{
+    extract_oz_url_from_script_calls, fetch_data_module, parse_oz_url_params,
+};
+use crate::application::utils::popup::close_popups;
 
 use crate::application::utils::sap_table::try_table_into_with_scroll;
-use crate::application::utils::semester::get_selected_semester;
+use crate::application::utils::semester::{get_selected_semester, semesters_between};
 use crate::client::{USaintApplication, USaintClient};
-use crate::{ApplicationError, RusaintError, model::SemesterType};
+use crate::{
+    ApplicationError, RusaintError,
+    model::{LectureCode, SemesterType},
+};
 use wdpe::command::WebDynproCommandExecutor;
 use wdpe::element::parser::ElementParser;
 use wdpe::{
     body::Body,
     command::element::{
         action::ButtonPressEventCommand,
-        complex::{SapTableBodyCommand, SapTableLSDataCommand},
+        complex::{SapTableBodyCommand, SapTableLSDataCommand, SapTableVerticalScrollEventCommand},
         selection::{
             ComboBoxChangeEventCommand, ComboBoxLSDataCommand, ComboBoxSelectEventCommand,
         },
     },
     define_elements,
     element::{
-        ElementDefWrapper,
+        ElementDefWrapper, ElementWrapper,
         action::Button,
         complex::{
             SapTable,
-            sap_table::cell::{SapTableCell, SapTableCellWrapper},
+            sap_table::{
+                SapTableRow,
+                cell::{SapTableCell, SapTableCellWrapper},
+            },
         },
         definition::ElementDefinition,
         selection::ComboBox,
     },
-    error::{ElementError, WebDynproError},
+    error::{BodyError, ElementError, WebDynproError},
+    event::Event,
+    scraper::Selector,
+    state::EventProcessResult,
 };
 
 /// [강의평가조회](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMB2W1010)
@@ -69,6 +88,7 @@ impl<'a> LectureAssessmentApplication {
 
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
+    /// 현재 학기는 [`current_semester`](crate::application::current_semester::current_semester)로 확인하세요.
     pub fn get_selected_semester(&self) -> Result<(u32, SemesterType), RusaintError> {
         Ok(get_selected_semester(
             &self.client,
@@ -86,7 +106,7 @@ impl<'a> LectureAssessmentApplication {
         year: &str,
         semester: SemesterType,
         lecture_name: Option<&str>,
-        lecture_code: Option<u32>,
+        lecture_code: Option<&LectureCode>,
         professor_name: Option<&str>,
     ) -> Result<(), WebDynproError> {
         let parser = ElementParser::new(self.body());
@@ -116,7 +136,7 @@ impl<'a> LectureAssessmentApplication {
         if let Some(lecture_code) = lecture_code {
             let event = parser.read(ComboBoxChangeEventCommand::new(
                 Self::ILSM_OBJID,
-                &lecture_code.to_string(),
+                lecture_code.as_str(),
                 false,
             ))?;
             self.client.process_event(false, event).await?;
@@ -140,7 +160,7 @@ impl<'a> LectureAssessmentApplication {
         year: u32,
         semester: SemesterType,
         lecture_name: Option<&str>,
-        lecture_code: Option<u32>,
+        lecture_code: Option<&LectureCode>,
         professor_name: Option<&str>,
     ) -> Result<Vec<LectureAssessmentResult>, RusaintError> {
         self.search(
@@ -179,6 +199,217 @@ impl<'a> LectureAssessmentApplication {
         Ok(try_table_into_with_scroll(&mut self.client, parser, Self::TABLE).await?)
     }
 
+    /// `from_year`부터 `to_year`까지 모든 학기(계절학기 포함)의 강의평가를 검색하여 교수별, 과목별로 집계합니다.
+    ///
+    /// 학기마다 검색을 수행하므로, 검색 조건 없이 넓은 범위를 집계하면 오랜 시간이 걸릴 수 있습니다.
+    /// 검색 결과가 없는 학기는 건너뛰며, [`CurrentSemester::today`]로 추정한 현재 학기 이후의 학기는 검색하지 않습니다.
+    pub async fn aggregate_assessments(
+        &mut self,
+        from_year: u32,
+        to_year: u32,
+        lecture_name: Option<&str>,
+        lecture_code: Option<&LectureCode>,
+        professor_name: Option<&str>,
+    ) -> Result<AssessmentAggregation, RusaintError> {
+        let current = CurrentSemester::today();
+        let until = (to_year, SemesterType::Winter).min((current.year(), current.semester()));
+        let mut results = Vec::new();
+        for (year, semester) in semesters_between(from_year, until) {
+            match self
+                .find_assessments(year, semester, lecture_name, lecture_code, professor_name)
+                .await
+            {
+                Ok(found) => results.extend(found),
+                Err(RusaintError::ApplicationError(ApplicationError::NoLectureAssessments)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(AssessmentAggregation::new(&results))
+    }
+
+    /// 주어진 강의평가 결과의 상세 화면을 열어 문항별 결과를 가져옵니다.
+    /// [`find_assessments()`](Self::find_assessments)를 먼저 호출하여 해당 결과가 포함된 학기를 검색한 이후에 사용되어야 합니다.
+    pub async fn assessment_detail(
+        &mut self,
+        result: &LectureAssessmentResult,
+    ) -> Result<LectureAssessmentDetail, RusaintError> {
+        close_popups(&mut self.client).await?;
+        let event = self.find_detail_event(result, None)?.ok_or_else(|| {
+            WebDynproError::from(ElementError::NoSuchData {
+                element: Self::TABLE.id().to_string(),
+                field: format!(
+                    "assessment detail of {} ({})",
+                    result.lecture_code(),
+                    result.professor()
+                ),
+            })
+        })?;
+        let questions = self.open_detail(event).await?;
+        Ok(LectureAssessmentDetail::new(result.clone(), questions))
+    }
+
+    /// 검색 조건에 맞는 강의평가 정보를 문항별 결과와 함께 가져옵니다.
+    /// 검색된 강의평가의 수 만큼 서버에 요청을 보내므로, 문항별 결과가 필요한 경우에만 사용하십시오.
+    pub async fn find_assessment_details(
+        &mut self,
+        year: u32,
+        semester: SemesterType,
+        lecture_name: Option<&str>,
+        lecture_code: Option<&LectureCode>,
+        professor_name: Option<&str>,
+    ) -> Result<Vec<LectureAssessmentDetail>, RusaintError> {
+        let results = self
+            .find_assessments(year, semester, lecture_name, lecture_code, professor_name)
+            .await?;
+        self.scroll_table_to(0).await?;
+        let mut details = Vec::with_capacity(results.len());
+        for (position, result) in results.into_iter().enumerate() {
+            close_popups(&mut self.client).await?;
+            let event = match self.find_detail_event(&result, Some(position))? {
+                Some(event) => Some(event),
+                None => {
+                    self.scroll_table_to(position).await?;
+                    self.find_detail_event(&result, Some(position))?
+                }
+            };
+            let Some(event) = event else {
+                return Err(WebDynproError::from(ElementError::NoSuchData {
+                    element: Self::TABLE.id().to_string(),
+                    field: format!(
+                        "assessment detail of {} ({})",
+                        result.lecture_code(),
+                        result.professor()
+                    ),
+                })
+                .into());
+            };
+            let questions = self.open_detail(event).await?;
+            details.push(LectureAssessmentDetail::new(result, questions));
+        }
+        Ok(details)
+    }
+
+    async fn scroll_table_to(&mut self, position: usize) -> Result<(), WebDynproError> {
+        let parser = ElementParser::new(self.body());
+        let event = parser.read(SapTableVerticalScrollEventCommand::new(
+            Self::TABLE,
+            position.try_into().unwrap_or(u32::MAX),
+            "",
+            "SCROLLBAR",
+            false,
+            false,
+            false,
+            false,
+        ))?;
+        self.client.process_event(false, event).await?;
+        Ok(())
+    }
+
+    /// 현재 표에 보이는 행 중 주어진 결과에 해당하는 행에서 상세 화면을 여는 이벤트를 찾습니다.
+    ///
+    /// 행의 모든 열이 주어진 결과와 같아야 하며, 검색 결과에서의 위치(`position`)가 주어졌다면 행 번호도 일치해야 합니다.
+    fn find_detail_event(
+        &self,
+        result: &LectureAssessmentResult,
+        position: Option<usize>,
+    ) -> Result<Option<Event>, RusaintError> {
+        let parser = ElementParser::new(self.body());
+        let table = parser.read(SapTableBodyCommand::new(Self::TABLE))?;
+        Ok(table.iter().find_map(|row| {
+            // 표의 행 번호(`rr`)는 1부터 시작합니다.
+            if let (Some(position), Some(row_index)) = (position, row.row_index())
+                && usize::try_from(row_index).ok() != Some(position + 1)
+            {
+                return None;
+            }
+            let row_result = row
+                .try_row_into::<LectureAssessmentResult>(table.header(), &parser)
+                .ok()?;
+            if row_result != *result {
+                return None;
+            }
+            Self::detail_event_from_row(row, &parser)
+        }))
+    }
+
+    fn detail_event_from_row(row: &SapTableRow, parser: &ElementParser) -> Option<Event> {
+        row.iter_value(parser)
+            .find_map(|cell| match cell.ok()?.content() {
+                Some(ElementDefWrapper::Link(link_def)) => parser
+                    .element_from_def(&link_def)
+                    .ok()?
+                    .activate(false, false)
+                    .ok(),
+                Some(ElementDefWrapper::Button(btn_def)) => {
+                    parser.element_from_def(&btn_def).ok()?.press().ok()
+                }
+                _ => None,
+            })
+    }
+
+    /// 상세 화면을 열고 팝업 창의 표 또는 OZ 리포트에서 문항별 결과를 읽습니다.
+    async fn open_detail(
+        &mut self,
+        event: Event,
+    ) -> Result<Vec<AssessmentQuestionResult>, RusaintError> {
+        let script_calls = match self.client.process_event(false, event).await? {
+            EventProcessResult::Sent(result) => result.script_calls.unwrap_or_default(),
+            EventProcessResult::Enqueued => Vec::new(),
+        };
+        let popup_rows = Self::popup_rows(self.body());
+        close_popups(&mut self.client).await?;
+        let rows = match popup_rows {
+            Ok(rows) => rows,
+            Err(err) if script_calls.is_empty() => return Err(err.into()),
+            Err(_) => {
+                let oz_url = extract_oz_url_from_script_calls(&script_calls)?;
+                let oz_params = parse_oz_url_params(&oz_url)?;
+                let response =
+                    fetch_data_module(&oz_params, Some(self.client.http_client())).await?;
+                Self::dataset_rows(&response.datasets)
+            }
+        };
+        let questions: Vec<AssessmentQuestionResult> = rows
+            .iter()
+            .filter_map(|row| AssessmentQuestionResult::from_labeled(row))
+            .collect();
+        if questions.is_empty() {
+            return Err(ApplicationError::NoAssessmentDetail.into());
+        }
+        Ok(questions)
+    }
+
+    fn popup_rows(body: &Body) -> Result<Vec<Vec<(String, String)>>, WebDynproError> {
+        let table_inside_popup_selector = Selector::parse(r#"[ct="PW"] [ct="ST"]"#).unwrap();
+        let parser = ElementParser::new(body);
+        let table_ref = parser
+            .document()
+            .select(&table_inside_popup_selector)
+            .next()
+            .ok_or(BodyError::NoSuchElement("Table in popup".to_string()))?;
+        let table_elem: SapTable<'_> = ElementWrapper::from_ref(table_ref)?.try_into()?;
+        let table_body = table_elem.table()?;
+        Ok(table_body
+            .iter()
+            .filter_map(|row| {
+                row.try_row_into::<Vec<(String, String)>>(table_body.header(), &parser)
+                    .ok()
+            })
+            .collect())
+    }
+
+    fn dataset_rows(datasets: &[DataSet]) -> Vec<Vec<(String, String)>> {
+        datasets
+            .iter()
+            .flat_map(|(_, rows)| rows)
+            .map(|row| {
+                row.iter()
+                    .map(|(name, value)| (name.clone(), value.to_string_repr()))
+                    .collect()
+            })
+            .collect()
+    }
+
     /// 페이지를 새로고침합니다.
     pub async fn reload(&mut self) -> Result<(), RusaintError> {
         self.client.reload().await?;
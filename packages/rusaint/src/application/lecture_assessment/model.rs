@@ -5,18 +5,25 @@ use serde::{
     de::{IntoDeserializer, value::MapDeserializer},
 };
 
-use crate::application::utils::de_with::{
-    deserialize_f32_string, deserialize_semester_type, deserialize_u32_string,
-};
-use crate::model::SemesterType;
+use crate::application::utils::de_with::{deserialize_f32_string, deserialize_semester_type};
+use crate::model::{LectureCode, SemesterType};
 use wdpe::element::parser::ElementParser;
 use wdpe::{
     element::{complex::sap_table::FromSapTable, definition::ElementDefinition},
     error::{ElementError, WebDynproError},
 };
 
+mod detail;
+mod statistics;
+
+pub use detail::{AssessmentQuestionResult, LectureAssessmentDetail};
+pub use statistics::{
+    AssessmentAggregation, AssessmentStatistics, AssessmentTrendPoint, CourseAssessmentStatistics,
+    ProfessorAssessmentStatistics,
+};
+
 /// 강의평가 결과
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct LectureAssessmentResult {
     #[serde(rename(deserialize = "년도"))]
@@ -26,11 +33,8 @@ pub struct LectureAssessmentResult {
         deserialize_with = "deserialize_semester_type"
     )]
     semester: SemesterType,
-    #[serde(
-        rename(deserialize = "과목코드"),
-        deserialize_with = "deserialize_u32_string"
-    )]
-    lecture_code: u32,
+    #[serde(rename(deserialize = "과목코드"))]
+    lecture_code: LectureCode,
     #[serde(rename(deserialize = "과목명"))]
     lecture_name: String,
     #[serde(
@@ -65,8 +69,8 @@ impl LectureAssessmentResult {
     }
 
     /// 과목 코드를 반환합니다.
-    pub fn lecture_code(&self) -> u32 {
-        self.lecture_code
+    pub fn lecture_code(&self) -> &LectureCode {
+        &self.lecture_code
     }
 
     /// 과목명을 반환합니다.
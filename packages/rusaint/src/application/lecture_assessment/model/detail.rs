@@ -0,0 +1,214 @@
+use serde::{Deserialize, Serialize};
+
+use super::LectureAssessmentResult;
+
+/// 강의평가의 문항별 결과
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct AssessmentQuestionResult {
+    /// 문항 번호
+    number: Option<u32>,
+    /// 평가 영역 (e.g. "강의준비", "강의내용")
+    category: String,
+    /// 문항 내용
+    question: String,
+    /// 문항 점수
+    score: Option<f32>,
+}
+
+impl AssessmentQuestionResult {
+    /// 문항 번호
+    pub fn number(&self) -> Option<u32> {
+        self.number
+    }
+
+    /// 평가 영역
+    pub fn category(&self) -> &str {
+        &self.category
+    }
+
+    /// 문항 내용
+    pub fn question(&self) -> &str {
+        &self.question
+    }
+
+    /// 문항 점수
+    pub fn score(&self) -> Option<f32> {
+        self.score
+    }
+
+    /// 열 이름과 값의 쌍으로 이루어진 한 행을 문항 결과로 해석합니다. 문항 내용이 없는 행(e.g. 합계 행)은 `None`입니다.
+    ///
+    /// 열 이름에 "번호", "영역" 또는 "구분", "문항" 또는 "질문", "점수" 또는 "평균"이 포함되어 있는지로 각 열을 구분합니다.
+    /// 같은 종류의 열이 여러 개라면(e.g. "평균점수"와 "학과평균") 가장 앞의 열을 사용합니다.
+    pub(crate) fn from_labeled(row: &[(String, String)]) -> Option<Self> {
+        let mut number = None;
+        let mut category = None;
+        let mut question = None;
+        let mut score = None;
+        for (label, value) in row {
+            let slot = if label.contains("번호") {
+                &mut number
+            } else if label.contains("영역") || label.contains("구분") {
+                &mut category
+            } else if label.contains("문항") || label.contains("질문") {
+                &mut question
+            } else if label.contains("점수") || label.contains("평균") {
+                &mut score
+            } else {
+                continue;
+            };
+            slot.get_or_insert(value.trim());
+        }
+        let question = question.filter(|question| !question.is_empty())?;
+        Some(Self {
+            number: number.and_then(|number| number.parse().ok()),
+            category: category.unwrap_or_default().to_string(),
+            question: question.to_string(),
+            score: score.and_then(|score| score.parse().ok()),
+        })
+    }
+}
+
+/// 문항별 결과를 포함한 강의평가 상세 결과
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct LectureAssessmentDetail {
+    /// 강의평가 결과
+    result: LectureAssessmentResult,
+    /// 문항별 결과
+    questions: Vec<AssessmentQuestionResult>,
+}
+
+impl LectureAssessmentDetail {
+    pub(crate) fn new(
+        result: LectureAssessmentResult,
+        questions: Vec<AssessmentQuestionResult>,
+    ) -> Self {
+        Self { result, questions }
+    }
+
+    /// 강의평가 결과
+    pub fn result(&self) -> &LectureAssessmentResult {
+        &self.result
+    }
+
+    /// 문항별 결과
+    pub fn questions(&self) -> &[AssessmentQuestionResult] {
+        &self.questions
+    }
+
+    /// 평가 영역별 평균 점수 (영역이 처음 나타난 순서)
+    pub fn category_scores(&self) -> Vec<(String, f32)> {
+        let mut categories: Vec<(String, f32, u32)> = Vec::new();
+        for question in &self.questions {
+            let Some(score) = question.score else {
+                continue;
+            };
+            match categories
+                .iter_mut()
+                .find(|(category, _, _)| *category == question.category)
+            {
+                Some((_, sum, count)) => {
+                    *sum += score;
+                    *count += 1;
+                }
+                None => categories.push((question.category.clone(), score, 1)),
+            }
+        }
+        categories
+            .into_iter()
+            .map(|(category, sum, count)| (category, sum / count as f32))
+            .collect()
+    }
+
+    /// 점수가 가장 낮은 문항
+    pub fn lowest_question(&self) -> Option<&AssessmentQuestionResult> {
+        self.questions
+            .iter()
+            .filter(|question| question.score.is_some())
+            .min_by(|a, b| {
+                a.score
+                    .unwrap_or_default()
+                    .total_cmp(&b.score.unwrap_or_default())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(cells: &[(&str, &str)]) -> Vec<(String, String)> {
+        cells
+            .iter()
+            .map(|(label, value)| (label.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn parses_question_rows() {
+        let rows = [
+            row(&[
+                ("문항번호", "1"),
+                ("평가영역", "강의준비"),
+                ("평가문항", "강의계획서에 따라 수업이 진행되었다."),
+                ("평균점수", "4.52"),
+            ]),
+            row(&[
+                ("문항번호", "2"),
+                ("평가영역", "강의준비"),
+                ("평가문항", "수업 자료가 충실하였다."),
+                ("평균점수", "4.10"),
+            ]),
+            row(&[
+                ("문항번호", "3"),
+                ("평가영역", "강의내용"),
+                ("평가문항", "수업 내용을 이해하기 쉬웠다."),
+                ("평균점수", ""),
+            ]),
+            row(&[
+                ("문항번호", ""),
+                ("평가영역", "합계"),
+                ("평가문항", ""),
+                ("평균점수", "8.62"),
+            ]),
+        ];
+        let questions: Vec<AssessmentQuestionResult> = rows
+            .iter()
+            .filter_map(|row| AssessmentQuestionResult::from_labeled(row))
+            .collect();
+        assert_eq!(questions.len(), 3);
+        assert_eq!(questions[0].number(), Some(1));
+        assert_eq!(questions[0].category(), "강의준비");
+        assert_eq!(questions[2].score(), None);
+        let result: LectureAssessmentResult = serde_json::from_value(serde_json::json!({
+            "년도": "2023", "학기": "2 학기", "과목코드": "2150001", "과목명": "마케팅원론",
+            "학점": "3.0", "교수명": "홍길동", "소속대학": "경영대학",
+            "소속학과": "경영학부", "직위명": "교수", "점수": "86.2",
+        }))
+        .unwrap();
+        let detail = LectureAssessmentDetail::new(result, questions);
+        let categories = detail.category_scores();
+        assert_eq!(categories.len(), 1);
+        assert!((categories[0].1 - 4.31).abs() < 1e-4);
+        assert_eq!(detail.lowest_question().unwrap().number(), Some(2));
+    }
+
+    #[test]
+    fn uses_first_column_of_each_kind() {
+        let question = AssessmentQuestionResult::from_labeled(&row(&[
+            ("문항번호", "4"),
+            ("평가구분", "강의평가"),
+            ("평가영역", "강의방법"),
+            ("평가문항", "질문에 성실히 답변하였다."),
+            ("평균점수", "4.30"),
+            ("학과평균", "4.05"),
+        ]))
+        .unwrap();
+        assert_eq!(question.number(), Some(4));
+        assert_eq!(question.category(), "강의평가");
+        assert_eq!(question.score(), Some(4.30));
+        assert!(AssessmentQuestionResult::from_labeled(&row(&[("NO", "1")])).is_none());
+    }
+}
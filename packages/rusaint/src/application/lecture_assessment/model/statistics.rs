@@ -0,0 +1,337 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::LectureAssessmentResult;
+use crate::model::{LectureCode, SemesterType};
+
+/// 한 학기의 강의평가 점수 요약
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct AssessmentTrendPoint {
+    /// 학년도
+    year: u32,
+    /// 학기
+    semester: SemesterType,
+    /// 평균 점수
+    mean_score: f32,
+    /// 강의(분반) 수
+    sections: u32,
+}
+
+impl AssessmentTrendPoint {
+    /// 학년도
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 학기
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 평균 점수
+    pub fn mean_score(&self) -> f32 {
+        self.mean_score
+    }
+
+    /// 강의(분반) 수
+    pub fn sections(&self) -> u32 {
+        self.sections
+    }
+}
+
+/// 여러 강의평가 결과의 점수 통계
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct AssessmentStatistics {
+    /// 평균 점수
+    mean_score: f32,
+    /// 최저 점수
+    min_score: f32,
+    /// 최고 점수
+    max_score: f32,
+    /// 강의(분반) 수
+    sections: u32,
+    /// 학기별 점수 추이 (오래된 학기부터)
+    trend: Vec<AssessmentTrendPoint>,
+}
+
+fn mean(scores: &[f32]) -> f32 {
+    scores.iter().sum::<f32>() / scores.len() as f32
+}
+
+impl AssessmentStatistics {
+    fn new(results: &[&LectureAssessmentResult]) -> Self {
+        let scores: Vec<f32> = results.iter().map(|result| result.score()).collect();
+        let mut by_semester: BTreeMap<(u32, SemesterType), Vec<f32>> = BTreeMap::new();
+        for result in results {
+            let year = result.year().trim().parse().unwrap_or_default();
+            by_semester
+                .entry((year, result.semester()))
+                .or_default()
+                .push(result.score());
+        }
+        let trend = by_semester
+            .into_iter()
+            .map(|((year, semester), scores)| AssessmentTrendPoint {
+                year,
+                semester,
+                mean_score: mean(&scores),
+                sections: scores.len() as u32,
+            })
+            .collect();
+        Self {
+            mean_score: mean(&scores),
+            min_score: scores.iter().copied().fold(f32::INFINITY, f32::min),
+            max_score: scores.iter().copied().fold(f32::NEG_INFINITY, f32::max),
+            sections: scores.len() as u32,
+            trend,
+        }
+    }
+
+    /// 평균 점수
+    pub fn mean_score(&self) -> f32 {
+        self.mean_score
+    }
+
+    /// 최저 점수
+    pub fn min_score(&self) -> f32 {
+        self.min_score
+    }
+
+    /// 최고 점수
+    pub fn max_score(&self) -> f32 {
+        self.max_score
+    }
+
+    /// 강의(분반) 수
+    pub fn sections(&self) -> u32 {
+        self.sections
+    }
+
+    /// 학기별 점수 추이 (오래된 학기부터)
+    pub fn trend(&self) -> &[AssessmentTrendPoint] {
+        &self.trend
+    }
+
+    /// 가장 오래된 학기 대비 가장 최근 학기의 평균 점수 변화량 (두 학기 이상의 기록이 있는 경우)
+    pub fn score_change(&self) -> Option<f32> {
+        match (self.trend.first(), self.trend.last()) {
+            (Some(first), Some(last)) if self.trend.len() > 1 => {
+                Some(last.mean_score - first.mean_score)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// 교수(강사)별 강의평가 통계
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ProfessorAssessmentStatistics {
+    /// 교수명
+    professor: String,
+    /// 소속학과
+    department: String,
+    /// 담당한 과목명
+    courses: Vec<String>,
+    /// 점수 통계
+    statistics: AssessmentStatistics,
+}
+
+impl ProfessorAssessmentStatistics {
+    /// 교수명
+    pub fn professor(&self) -> &str {
+        &self.professor
+    }
+
+    /// 소속학과
+    pub fn department(&self) -> &str {
+        &self.department
+    }
+
+    /// 담당한 과목명
+    pub fn courses(&self) -> &[String] {
+        &self.courses
+    }
+
+    /// 점수 통계
+    pub fn statistics(&self) -> &AssessmentStatistics {
+        &self.statistics
+    }
+}
+
+/// 과목별 강의평가 통계
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct CourseAssessmentStatistics {
+    /// 과목 코드
+    lecture_code: LectureCode,
+    /// 과목명
+    lecture_name: String,
+    /// 담당한 교수명
+    professors: Vec<String>,
+    /// 점수 통계
+    statistics: AssessmentStatistics,
+}
+
+impl CourseAssessmentStatistics {
+    /// 과목 코드
+    pub fn lecture_code(&self) -> &LectureCode {
+        &self.lecture_code
+    }
+
+    /// 과목명
+    pub fn lecture_name(&self) -> &str {
+        &self.lecture_name
+    }
+
+    /// 담당한 교수명
+    pub fn professors(&self) -> &[String] {
+        &self.professors
+    }
+
+    /// 점수 통계
+    pub fn statistics(&self) -> &AssessmentStatistics {
+        &self.statistics
+    }
+}
+
+/// 여러 학기의 강의평가 결과를 교수별, 과목별로 집계한 결과
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct AssessmentAggregation {
+    /// 교수별 통계 (평균 점수가 높은 순)
+    professors: Vec<ProfessorAssessmentStatistics>,
+    /// 과목별 통계 (평균 점수가 높은 순)
+    courses: Vec<CourseAssessmentStatistics>,
+}
+
+fn distinct(values: impl Iterator<Item = String>) -> Vec<String> {
+    let mut values: Vec<String> = values.collect();
+    values.sort();
+    values.dedup();
+    values
+}
+
+impl AssessmentAggregation {
+    /// 강의평가 결과를 교수(교수명과 소속학과)별, 과목(과목 코드)별로 집계합니다.
+    pub fn new<'a>(results: impl IntoIterator<Item = &'a LectureAssessmentResult>) -> Self {
+        let mut by_professor: BTreeMap<(&str, &str), Vec<&LectureAssessmentResult>> =
+            BTreeMap::new();
+        let mut by_course: BTreeMap<&LectureCode, Vec<&LectureAssessmentResult>> = BTreeMap::new();
+        for result in results {
+            by_professor
+                .entry((result.professor(), result.department()))
+                .or_default()
+                .push(result);
+            by_course
+                .entry(result.lecture_code())
+                .or_default()
+                .push(result);
+        }
+        let mut professors: Vec<ProfessorAssessmentStatistics> = by_professor
+            .into_iter()
+            .map(
+                |((professor, department), results)| ProfessorAssessmentStatistics {
+                    professor: professor.to_string(),
+                    department: department.to_string(),
+                    courses: distinct(
+                        results
+                            .iter()
+                            .map(|result| result.lecture_name().to_string()),
+                    ),
+                    statistics: AssessmentStatistics::new(&results),
+                },
+            )
+            .collect();
+        let mut courses: Vec<CourseAssessmentStatistics> = by_course
+            .into_iter()
+            .map(|(lecture_code, results)| CourseAssessmentStatistics {
+                lecture_code: lecture_code.clone(),
+                // 가장 최근 학기의 과목명을 사용합니다.
+                lecture_name: results
+                    .iter()
+                    .max_by_key(|result| (result.year(), result.semester()))
+                    .map(|result| result.lecture_name().to_string())
+                    .unwrap_or_default(),
+                professors: distinct(results.iter().map(|result| result.professor().to_string())),
+                statistics: AssessmentStatistics::new(&results),
+            })
+            .collect();
+        professors.sort_by(|a, b| b.statistics.mean_score.total_cmp(&a.statistics.mean_score));
+        courses.sort_by(|a, b| b.statistics.mean_score.total_cmp(&a.statistics.mean_score));
+        Self {
+            professors,
+            courses,
+        }
+    }
+
+    /// 교수별 통계 (평균 점수가 높은 순)
+    pub fn professors(&self) -> &[ProfessorAssessmentStatistics] {
+        &self.professors
+    }
+
+    /// 과목별 통계 (평균 점수가 높은 순)
+    pub fn courses(&self) -> &[CourseAssessmentStatistics] {
+        &self.courses
+    }
+
+    /// 교수명으로 통계를 찾습니다. 동명이인이 있다면 모두 반환합니다.
+    pub fn professor<'a>(
+        &'a self,
+        professor: &'a str,
+    ) -> impl Iterator<Item = &'a ProfessorAssessmentStatistics> {
+        self.professors
+            .iter()
+            .filter(move |statistics| statistics.professor == professor)
+    }
+
+    /// 과목 코드로 통계를 찾습니다.
+    pub fn course(&self, lecture_code: &LectureCode) -> Option<&CourseAssessmentStatistics> {
+        self.courses
+            .iter()
+            .find(|statistics| &statistics.lecture_code == lecture_code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(
+        year: &str,
+        semester: &str,
+        code: &str,
+        professor: &str,
+        score: &str,
+    ) -> LectureAssessmentResult {
+        serde_json::from_value(serde_json::json!({
+            "년도": year, "학기": semester, "과목코드": code, "과목명": "마케팅원론",
+            "학점": "3.0", "교수명": professor, "소속대학": "경영대학",
+            "소속학과": "경영학부", "직위명": "교수", "점수": score,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn aggregates_by_professor_and_course() {
+        let results = vec![
+            result("2022", "1 학기", "2150001", "홍길동", "80.0"),
+            result("2022", "1 학기", "2150001", "김철수", "90.0"),
+            result("2023", "2 학기", "2150001", "홍길동", "90.0"),
+            result("2023", "2 학기", "2150002", "홍길동", "94.0"),
+        ];
+        let aggregation = AssessmentAggregation::new(&results);
+        let hong = aggregation.professor("홍길동").next().unwrap();
+        assert_eq!(hong.statistics().sections(), 3);
+        assert_eq!(hong.statistics().trend().len(), 2);
+        assert_eq!(hong.statistics().trend()[0].year(), 2022);
+        assert_eq!(hong.statistics().score_change(), Some(12.0));
+        let course = aggregation.course(&"2150001".into()).unwrap();
+        assert_eq!(course.professors(), ["김철수", "홍길동"]);
+        assert!((course.statistics().mean_score() - 260.0 / 3.0).abs() < 1e-4);
+        assert_eq!(aggregation.courses()[0].lecture_code(), "2150002");
+    }
+}
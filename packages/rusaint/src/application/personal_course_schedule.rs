@@ -1,6 +1,4 @@
-use std::collections::HashMap;
-
-use model::{CourseScheduleInformation, PersonalCourseSchedule, Weekday};
+use model::{PersonalCourseSchedule, parse_schedule_table};
 
 use crate::application::utils::semester::get_selected_semester;
 use crate::client::{USaintApplication, USaintClient};
@@ -80,6 +78,7 @@ impl<'a> PersonalCourseScheduleApplication {
 
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
+    /// 현재 학기는 [`current_semester`](crate::application::current_semester::current_semester)로 확인하세요.
     pub fn get_selected_semester(&self) -> Result<(u32, SemesterType), RusaintError> {
         Ok(get_selected_semester(
             &self.client,
@@ -102,77 +101,8 @@ impl<'a> PersonalCourseScheduleApplication {
                 let table_body = table.table()?;
                 let row_string: Vec<Vec<Option<String>>> =
                     table_body.try_table_into::<Vec<Option<String>>>(&parser)?;
-                let mut schedule: HashMap<Weekday, Vec<CourseScheduleInformation>> =
-                    Default::default();
-                for row in row_string.into_iter().skip(1) {
-                    row.into_iter()
-                        .skip(1)
-                        .enumerate()
-                        .filter_map(|(col_idx, option)| option.map(|str| (col_idx, str)))
-                        .for_each(|(col_idx, str)| match col_idx {
-                            0 => {
-                                schedule.entry(Weekday::Mon).or_default();
-                                let mut iter = str.split("\n").peekable();
-                                while iter.peek().is_some() {
-                                    schedule
-                                        .get_mut(&Weekday::Mon)
-                                        .unwrap()
-                                        .push(CourseScheduleInformation::from_iter(&mut iter))
-                                }
-                            }
-                            1 => {
-                                schedule.entry(Weekday::Tue).or_default();
-                                let mut iter = str.split("\n").peekable();
-                                while iter.peek().is_some() {
-                                    schedule
-                                        .get_mut(&Weekday::Tue)
-                                        .unwrap()
-                                        .push(CourseScheduleInformation::from_iter(&mut iter))
-                                }
-                            }
-                            2 => {
-                                schedule.entry(Weekday::Wed).or_default();
-                                let mut iter = str.split("\n").peekable();
-                                while iter.peek().is_some() {
-                                    schedule
-                                        .get_mut(&Weekday::Wed)
-                                        .unwrap()
-                                        .push(CourseScheduleInformation::from_iter(&mut iter))
-                                }
-                            }
-                            3 => {
-                                schedule.entry(Weekday::Thu).or_default();
-                                let mut iter = str.split("\n").peekable();
-                                while iter.peek().is_some() {
-                                    schedule
-                                        .get_mut(&Weekday::Thu)
-                                        .unwrap()
-                                        .push(CourseScheduleInformation::from_iter(&mut iter))
-                                }
-                            }
-                            4 => {
-                                schedule.entry(Weekday::Fri).or_default();
-                                let mut iter = str.split("\n").peekable();
-                                while iter.peek().is_some() {
-                                    schedule
-                                        .get_mut(&Weekday::Fri)
-                                        .unwrap()
-                                        .push(CourseScheduleInformation::from_iter(&mut iter))
-                                }
-                            }
-                            5 => {
-                                schedule.entry(Weekday::Sat).or_default();
-                                let mut iter = str.split("\n").peekable();
-                                while iter.peek().is_some() {
-                                    schedule
-                                        .get_mut(&Weekday::Sat)
-                                        .unwrap()
-                                        .push(CourseScheduleInformation::from_iter(&mut iter))
-                                }
-                            }
-                            _ => {}
-                        });
-                }
+                let schedule = parse_schedule_table(row_string)
+                    .map_err(|err| RusaintError::ApplicationError(err.into()))?;
                 Ok(PersonalCourseSchedule::new(schedule))
             }
             Err(err) => match err {
@@ -196,3 +126,9 @@ impl<'a> PersonalCourseScheduleApplication {
 
 /// [`PersonalCourseSchedule`] 애플리케이션에 사용되는 데이터
 pub mod model;
+
+/// 개인수업시간표와 수강신청 내역의 비교 및 연결
+pub mod reconciliation;
+
+/// [`PersonalCourseSchedule`]과 채플 일정을 iCalendar로 내보내기 위한 도구
+pub mod export;
@@ -0,0 +1,149 @@
+use std::sync::LazyLock;
+
+use chrono::{Datelike, NaiveDate, TimeDelta};
+use regex_lite::Regex;
+
+use super::model::{PersonalCourseSchedule, Weekday, parse_time_range};
+use crate::application::chapel::model::ChapelInformation;
+use crate::export::{CalendarEvent, to_ics};
+
+/// 채플 시간 표기에 포함된 시간 범위 (e.g. "화 10:30-11:20")
+static CHAPEL_TIME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\d{1,2}:\d{2}\s*[-~]\s*\d{1,2}:\d{2}").unwrap());
+
+/// 주어진 날짜 이후 처음으로 해당 요일이 되는 날짜를 반환합니다.
+fn first_weekday_from(date: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let target = chrono::Weekday::from(weekday).num_days_from_monday();
+    let current = date.weekday().num_days_from_monday();
+    date + TimeDelta::days(((7 + target - current) % 7) as i64)
+}
+
+impl PersonalCourseSchedule {
+    /// 시간표의 각 수업을 학기 시작일부터 종료일까지 매주 반복되는 일정으로 변환합니다.
+    ///
+    /// 수업 시간을 해석할 수 없는 수업은 제외됩니다.
+    pub fn calendar_events(&self, start: NaiveDate, end: NaiveDate) -> Vec<CalendarEvent> {
+        let mut weekdays: Vec<&Weekday> = self.schedule().keys().collect();
+        weekdays.sort();
+        let mut events = Vec::new();
+        for weekday in weekdays {
+            let date = first_weekday_from(start, *weekday);
+            if date > end {
+                continue;
+            }
+            for class in &self.schedule()[weekday] {
+                let (Some(class_start), Some(class_end)) = (class.start(), class.end()) else {
+                    continue;
+                };
+                let description = match (class.code(), class.professor()) {
+                    (Some(code), "") => code.to_string(),
+                    (Some(code), professor) => format!("{professor} ({code})"),
+                    (None, professor) => professor.to_string(),
+                };
+                events.push(
+                    CalendarEvent::new(
+                        class.name(),
+                        date.and_time(class_start),
+                        date.and_time(class_end),
+                    )
+                    .with_location(class.classroom())
+                    .with_description(description)
+                    .weekly_until(end),
+                );
+            }
+        }
+        events
+    }
+
+    /// 시간표를 학기 시작일부터 종료일까지 반복되는 iCalendar 문서로 변환합니다. 채플 정보가 주어지면 채플 일정도 포함합니다.
+    pub fn to_ics(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        chapel: Option<&ChapelInformation>,
+    ) -> String {
+        let mut events = self.calendar_events(start, end);
+        if let Some(chapel) = chapel {
+            events.extend(chapel_events(chapel));
+        }
+        to_ics("개인수업시간표", &events)
+    }
+}
+
+/// 채플 출결 정보의 각 수업일을 일정으로 변환합니다.
+///
+/// 채플 시간에서 시간 범위를 찾을 수 없다면 하루 종일 진행되는 일정으로 만들고, 수업일자가 없는 수업은 제외합니다.
+pub fn chapel_events(chapel: &ChapelInformation) -> Vec<CalendarEvent> {
+    let general = chapel.general_information();
+    let time = CHAPEL_TIME
+        .find(general.chapel_time())
+        .and_then(|time| parse_time_range(time.as_str()));
+    chapel
+        .attendances()
+        .iter()
+        .filter_map(|attendance| {
+            let date = attendance.class_date()?;
+            let summary = if attendance.title().is_empty() {
+                "채플".to_string()
+            } else {
+                format!("채플: {}", attendance.title())
+            };
+            let event = match time {
+                Some((start, end)) => {
+                    CalendarEvent::new(summary, date.and_time(start), date.and_time(end))
+                }
+                None => CalendarEvent::all_day(summary, date),
+            }
+            .with_location(general.chapel_room())
+            .with_description(attendance.instructor());
+            Some(event)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::application::personal_course_schedule::model::CourseScheduleInformation;
+
+    #[test]
+    fn converts_schedule_into_weekly_events() {
+        let schedule = PersonalCourseSchedule::new(HashMap::from([(
+            Weekday::Wed,
+            vec![
+                CourseScheduleInformation::new(
+                    "데이터베이스".to_string(),
+                    "홍길동".to_string(),
+                    "10:30-11:45".to_string(),
+                    "정보과학관 21203".to_string(),
+                    Some("2150001".into()),
+                ),
+                CourseScheduleInformation::new(
+                    "온라인 강의".to_string(),
+                    "".to_string(),
+                    "".to_string(),
+                    "".to_string(),
+                    None,
+                ),
+            ],
+        )]));
+        // 2025-03-01은 토요일이므로 첫 수요일은 2025-03-05입니다.
+        let start = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 6, 20).unwrap();
+        let events = schedule.calendar_events(start, end);
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].start(),
+            NaiveDate::from_ymd_opt(2025, 3, 5)
+                .unwrap()
+                .and_hms_opt(10, 30, 0)
+                .unwrap()
+        );
+        assert_eq!(events[0].description(), "홍길동 (2150001)");
+        assert_eq!(events[0].repeat_until(), Some(end));
+        let ics = schedule.to_ics(start, end, None);
+        assert!(ics.contains("LOCATION:정보과학관 21203\r\n"));
+    }
+}
@@ -1,8 +1,19 @@
+use chrono::{Datelike, NaiveDateTime, NaiveTime};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
+
+use crate::application::utils::de_with::parse_time;
+use crate::model::{LectureCode, LectureSection};
+
+mod parser;
+mod registration;
+
+pub use parser::ScheduleParseError;
+pub(crate) use parser::parse_schedule_table;
+pub(crate) use registration::{normalize_name, normalize_time_range};
 
 /// 한 주의 요일을 표현합니다.
-#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum Weekday {
     /// 월요일
@@ -21,6 +32,65 @@ pub enum Weekday {
     Sun = 6,
 }
 
+impl Display for Weekday {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Self::Mon => "월",
+            Self::Tue => "화",
+            Self::Wed => "수",
+            Self::Thu => "목",
+            Self::Fri => "금",
+            Self::Sat => "토",
+            Self::Sun => "일",
+        };
+        write!(f, "{str}")
+    }
+}
+
+impl From<chrono::Weekday> for Weekday {
+    fn from(value: chrono::Weekday) -> Self {
+        match value {
+            chrono::Weekday::Mon => Weekday::Mon,
+            chrono::Weekday::Tue => Weekday::Tue,
+            chrono::Weekday::Wed => Weekday::Wed,
+            chrono::Weekday::Thu => Weekday::Thu,
+            chrono::Weekday::Fri => Weekday::Fri,
+            chrono::Weekday::Sat => Weekday::Sat,
+            chrono::Weekday::Sun => Weekday::Sun,
+        }
+    }
+}
+
+impl From<Weekday> for chrono::Weekday {
+    fn from(value: Weekday) -> Self {
+        match value {
+            Weekday::Mon => chrono::Weekday::Mon,
+            Weekday::Tue => chrono::Weekday::Tue,
+            Weekday::Wed => chrono::Weekday::Wed,
+            Weekday::Thu => chrono::Weekday::Thu,
+            Weekday::Fri => chrono::Weekday::Fri,
+            Weekday::Sat => chrono::Weekday::Sat,
+            Weekday::Sun => chrono::Weekday::Sun,
+        }
+    }
+}
+
+impl Weekday {
+    /// 한글 요일 표기(e.g. "월", "월요일")를 해석합니다.
+    pub(crate) fn from_korean(value: &str) -> Option<Weekday> {
+        match value.trim().trim_end_matches("요일") {
+            "월" => Some(Weekday::Mon),
+            "화" => Some(Weekday::Tue),
+            "수" => Some(Weekday::Wed),
+            "목" => Some(Weekday::Thu),
+            "금" => Some(Weekday::Fri),
+            "토" => Some(Weekday::Sat),
+            "일" => Some(Weekday::Sun),
+            _ => None,
+        }
+    }
+}
+
 /// 개인의 수업 시간표 정보를 조회합니다.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
@@ -36,19 +106,36 @@ pub struct CourseScheduleInformation {
     professor: String,
     time: String,
     classroom: String,
+    start: Option<NaiveTime>,
+    end: Option<NaiveTime>,
+    code: Option<LectureCode>,
+    division: Option<String>,
+}
+
+/// "hh:mm-hh:mm" 형태의 시간 범위를 시작 시각과 종료 시각으로 해석합니다.
+pub(crate) fn parse_time_range(time: &str) -> Option<(NaiveTime, NaiveTime)> {
+    let (start, end) = time.split_once(['-', '~'])?;
+    Some((parse_time(start)?, parse_time(end)?))
 }
 
 impl CourseScheduleInformation {
-    pub(crate) fn from_iter<'a>(
-        iter: &mut impl Iterator<Item = &'a str>,
+    pub(crate) fn new(
+        name: String,
+        professor: String,
+        time: String,
+        classroom: String,
+        code: Option<LectureCode>,
     ) -> CourseScheduleInformation {
-        let mut iter = iter.skip_while(|s| s.is_empty());
-        // Consume empty strings at start
+        let (start, end) = parse_time_range(&time).unzip();
         CourseScheduleInformation {
-            name: iter.next().unwrap().to_string(),
-            professor: iter.next().unwrap().to_string(),
-            time: iter.next().unwrap().to_string(),
-            classroom: iter.next().unwrap_or("").to_string(),
+            name,
+            professor,
+            time,
+            classroom,
+            start,
+            end,
+            code,
+            division: None,
         }
     }
 
@@ -71,6 +158,39 @@ impl CourseScheduleInformation {
     pub fn classroom(&self) -> &str {
         &self.classroom
     }
+
+    /// 강의 시작 시각을 반환합니다.
+    pub fn start(&self) -> Option<NaiveTime> {
+        self.start
+    }
+
+    /// 강의 종료 시각을 반환합니다.
+    pub fn end(&self) -> Option<NaiveTime> {
+        self.end
+    }
+
+    /// 주어진 시각이 강의 시간에 포함되는지 여부를 반환합니다.
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        matches!((self.start, self.end), (Some(start), Some(end)) if start <= time && time < end)
+    }
+
+    /// 과목번호를 반환합니다.
+    /// 개인수업시간표에서 가져온 정보는 [`PersonalCourseSchedule::link_registered_lectures`]로 수강신청 내역과 연결한 경우에만 과목번호가 있습니다.
+    pub fn code(&self) -> Option<&LectureCode> {
+        self.code.as_ref()
+    }
+
+    /// 분반을 반환합니다. 수강신청 내역과 연결한 경우에만 값이 있습니다.
+    pub fn division(&self) -> Option<&str> {
+        self.division.as_deref()
+    }
+
+    /// 과목번호와 분반을 반환합니다. 과목번호가 있는 경우에만 값이 있습니다.
+    pub fn section(&self) -> Option<LectureSection> {
+        self.code
+            .clone()
+            .map(|code| LectureSection::new(code, self.division.clone()))
+    }
 }
 
 impl PersonalCourseSchedule {
@@ -82,4 +202,37 @@ impl PersonalCourseSchedule {
     pub fn schedule(&self) -> &HashMap<Weekday, Vec<CourseScheduleInformation>> {
         &self.schedule
     }
+
+    /// 해당 요일의 주어진 시각에 진행 중인 수업을 반환합니다.
+    pub fn class_at(
+        &self,
+        weekday: Weekday,
+        time: NaiveTime,
+    ) -> Option<&CourseScheduleInformation> {
+        self.schedule
+            .get(&weekday)?
+            .iter()
+            .find(|class| class.contains(time))
+    }
+
+    /// 주어진 일시에 진행 중인 수업을 반환합니다.
+    pub fn class_at_datetime(
+        &self,
+        date_time: NaiveDateTime,
+    ) -> Option<&CourseScheduleInformation> {
+        self.class_at(date_time.weekday().into(), date_time.time())
+    }
+
+    /// 해당 요일의 주어진 시각 이후에 시작하는 가장 빠른 수업을 반환합니다.
+    pub fn next_class(
+        &self,
+        weekday: Weekday,
+        time: NaiveTime,
+    ) -> Option<&CourseScheduleInformation> {
+        self.schedule
+            .get(&weekday)?
+            .iter()
+            .filter(|class| class.start.is_some_and(|start| start >= time))
+            .min_by_key(|class| class.start)
+    }
 }
@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use super::{CourseScheduleInformation, Weekday, parse_time_range};
+
+/// 개인수업시간표 표를 해석하지 못했을 때 반환하는 오류
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ScheduleParseError {
+    /// 수업 시간이 없는 셀
+    #[error("No class time found in schedule cell: {cell:?}")]
+    MissingTime {
+        /// 해석하지 못한 셀의 내용
+        cell: String,
+    },
+    /// 수업 시간 앞에 강의명이 없는 셀
+    #[error("No class name found before class time in schedule cell: {cell:?}")]
+    MissingName {
+        /// 해석하지 못한 셀의 내용
+        cell: String,
+    },
+}
+
+/// 헤더 행으로 열 번호별 요일을 찾습니다. 헤더에서 요일을 찾을 수 없는 열은 월요일부터 순서대로 간주합니다.
+fn weekday_columns(header: Option<&Vec<Option<String>>>, columns: usize) -> Vec<Option<Weekday>> {
+    const ORDER: [Weekday; 7] = [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ];
+    (0..columns)
+        .map(|idx| {
+            header
+                .and_then(|header| header.get(idx + 1))
+                .and_then(|cell| cell.as_deref())
+                .and_then(Weekday::from_korean)
+                .or_else(|| ORDER.get(idx).copied())
+        })
+        .collect()
+}
+
+/// 셀의 한 수업 묶음(빈 줄로 구분된 줄들)을 수업 정보로 해석합니다.
+///
+/// 각 수업은 강의명(여러 줄일 수 있음), 교수명, 수업 시간, 강의실(생략 가능) 순서로 나타나며,
+/// 한 묶음 안에 여러 수업이 이어서 나타날 수 있습니다.
+fn parse_group(
+    lines: &[&str],
+    cell: &str,
+) -> Result<Vec<CourseScheduleInformation>, ScheduleParseError> {
+    let time_indices: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| parse_time_range(line).is_some())
+        .map(|(idx, _)| idx)
+        .collect();
+    if time_indices.is_empty() {
+        return Err(ScheduleParseError::MissingTime {
+            cell: cell.to_string(),
+        });
+    }
+    let mut classes = Vec::with_capacity(time_indices.len());
+    let mut header_start = 0;
+    for (n, &time_idx) in time_indices.iter().enumerate() {
+        let header = &lines[header_start..time_idx];
+        let Some((professor, name)) = header.split_last() else {
+            return Err(ScheduleParseError::MissingName {
+                cell: cell.to_string(),
+            });
+        };
+        let (name, professor) = if name.is_empty() {
+            (professor.to_string(), String::new())
+        } else {
+            (name.join(" "), professor.to_string())
+        };
+        let trailing = match time_indices.get(n + 1) {
+            Some(&next_time_idx) => &lines[time_idx + 1..next_time_idx],
+            None => &lines[time_idx + 1..],
+        };
+        // 다음 수업이 있다면 강의명과 교수명 두 줄을 남기고, 그보다 많은 줄이 있으면서 첫 줄이 강의실 형태일 때만 강의실로 봅니다.
+        let classroom = match time_indices.get(n + 1) {
+            Some(_) if trailing.len() >= 3 && is_classroom(trailing[0]) => trailing[0].to_string(),
+            Some(_) => String::new(),
+            None => trailing.join(" "),
+        };
+        header_start = time_idx + 1 + usize::from(!classroom.is_empty());
+        classes.push(CourseScheduleInformation::new(
+            name,
+            professor,
+            lines[time_idx].to_string(),
+            classroom,
+            None,
+        ));
+    }
+    Ok(classes)
+}
+
+/// 강의실 형태(e.g. "정보과학관 21203")인지, 즉 마지막 단어에 호실 번호가 있는지 확인합니다.
+fn is_classroom(line: &str) -> bool {
+    line.split_whitespace()
+        .last()
+        .is_some_and(|room| room.chars().any(|c| c.is_ascii_digit()))
+}
+
+/// 셀의 내용을 수업 정보 목록으로 해석합니다.
+pub(crate) fn parse_cell(cell: &str) -> Result<Vec<CourseScheduleInformation>, ScheduleParseError> {
+    let lines: Vec<&str> = cell.lines().map(str::trim).collect();
+    let mut classes = Vec::new();
+    for group in lines.split(|line| line.is_empty()) {
+        if group.is_empty() {
+            continue;
+        }
+        classes.extend(parse_group(group, cell)?);
+    }
+    Ok(classes)
+}
+
+/// 개인수업시간표 표의 행들을 요일별 수업 정보로 해석합니다.
+///
+/// 첫 행은 요일 헤더, 각 행의 첫 열은 교시 정보로 간주합니다.
+/// 여러 교시에 걸친 수업이 각 교시마다 반복되어 나타나더라도 한 번만 포함됩니다.
+pub(crate) fn parse_schedule_table(
+    rows: Vec<Vec<Option<String>>>,
+) -> Result<HashMap<Weekday, Vec<CourseScheduleInformation>>, ScheduleParseError> {
+    let columns = rows
+        .iter()
+        .map(|row| row.len().saturating_sub(1))
+        .max()
+        .unwrap_or(0);
+    let weekdays = weekday_columns(rows.first(), columns);
+    let mut schedule: HashMap<Weekday, Vec<CourseScheduleInformation>> = HashMap::new();
+    for row in rows.iter().skip(1) {
+        for (col_idx, cell) in row.iter().skip(1).enumerate() {
+            let (Some(weekday), Some(cell)) = (weekdays[col_idx], cell) else {
+                continue;
+            };
+            if cell.trim().is_empty() {
+                continue;
+            }
+            let classes = schedule.entry(weekday).or_default();
+            for class in parse_cell(cell)? {
+                let duplicated = classes
+                    .iter()
+                    .any(|existing| existing.name == class.name && existing.time == class.time);
+                if !duplicated {
+                    classes.push(class);
+                }
+            }
+        }
+    }
+    Ok(schedule)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(cells: &[&str]) -> Vec<Option<String>> {
+        cells
+            .iter()
+            .map(|cell| (!cell.is_empty()).then(|| cell.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn parses_schedule_table() {
+        let rows = vec![
+            row(&["", "월", "화", "수", "목", "금", "토", "일"]),
+            row(&[
+                "1\n09:00",
+                "데이터베이스\n홍길동\n09:00-10:15\n정보과학관 21203",
+                "",
+                "데이터베이스\n홍길동\n09:00-10:15\n정보과학관 21203",
+                "",
+                "",
+                "",
+                "",
+            ]),
+            row(&[
+                "2\n10:30",
+                "데이터베이스\n홍길동\n09:00-10:15\n정보과학관 21203",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "봉사활동\n10:30-12:00",
+            ]),
+            row(&[
+                "야간\n19:00",
+                "",
+                "컴퓨터 그래픽스\n특론\n김교수\n19:00-20:15\n\n캡스톤디자인\n이교수\n20:30-21:45\n형남공학관 50101",
+                "",
+                "",
+                "",
+                "",
+                "",
+            ]),
+        ];
+        let schedule = parse_schedule_table(rows).unwrap();
+        assert_eq!(schedule[&Weekday::Mon].len(), 1);
+        assert_eq!(schedule[&Weekday::Wed].len(), 1);
+        let sunday = &schedule[&Weekday::Sun][0];
+        assert_eq!(sunday.name(), "봉사활동");
+        assert_eq!(sunday.professor(), "");
+        let tuesday = &schedule[&Weekday::Tue];
+        assert_eq!(tuesday.len(), 2);
+        assert_eq!(tuesday[0].name(), "컴퓨터 그래픽스 특론");
+        assert_eq!(tuesday[0].professor(), "김교수");
+        assert_eq!(tuesday[0].classroom(), "");
+        assert_eq!(tuesday[1].classroom(), "형남공학관 50101");
+        assert!(tuesday[1].start().is_some());
+    }
+
+    #[test]
+    fn parses_consecutive_entries() {
+        let classes = parse_cell(
+            "운영체제\n박교수\n10:30-11:45\n정보과학관 21204\n네트워크\n최교수\n10:30-11:45",
+        )
+        .unwrap();
+        assert_eq!(classes.len(), 2);
+        assert_eq!(classes[0].classroom(), "정보과학관 21204");
+        assert_eq!(classes[1].name(), "네트워크");
+        assert_eq!(classes[1].professor(), "최교수");
+    }
+
+    #[test]
+    fn parses_multiline_names_after_classroom() {
+        let classes = parse_cell(
+            "데이터베이스\n홍길동\n09:00-10:15\n정보과학관 21203\n(외국인을위한)\n대학글쓰기\n김지학\n11:00-11:50\n전산관 19330",
+        )
+        .unwrap();
+        assert_eq!(classes.len(), 2);
+        assert_eq!(classes[0].classroom(), "정보과학관 21203");
+        assert_eq!(classes[1].name(), "(외국인을위한) 대학글쓰기");
+        assert_eq!(classes[1].professor(), "김지학");
+        assert_eq!(classes[1].classroom(), "전산관 19330");
+
+        let classes = parse_cell(
+            "채플\n교목실\n10:30-11:20\n(외국인을위한)\n대학글쓰기\n김지학\n11:00-11:50\n전산관 19330",
+        )
+        .unwrap();
+        assert_eq!(classes[0].classroom(), "");
+        assert_eq!(classes[1].name(), "(외국인을위한) 대학글쓰기");
+        assert_eq!(classes[1].classroom(), "전산관 19330");
+    }
+
+    #[test]
+    fn reports_offending_cell() {
+        let err = parse_cell("데이터베이스\n홍길동").unwrap_err();
+        assert_eq!(
+            err,
+            ScheduleParseError::MissingTime {
+                cell: "데이터베이스\n홍길동".to_string()
+            }
+        );
+        assert!(matches!(
+            parse_cell("09:00-10:15\n정보과학관"),
+            Err(ScheduleParseError::MissingName { .. })
+        ));
+    }
+}
@@ -0,0 +1,211 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use regex_lite::Regex;
+
+use super::{CourseScheduleInformation, PersonalCourseSchedule, Weekday};
+use crate::application::course_registration_status::model::RegisteredLecture;
+
+/// 강의시간(강의실) 표기의 한 항목 (e.g. "월 수 10:30-11:45 (정보과학관 21203-홍길동)")
+static SCHEDULE_ROOM: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"((?:[월화수목금토일]\s*)+)\s*(\d{1,2}:\d{2})\s*[-~]\s*(\d{1,2}:\d{2})(?:\s*\(([^)]*)\))?",
+    )
+    .unwrap()
+});
+
+/// 시간 범위 표기를 "hh:mm-hh:mm" 형태로 정규화합니다. (e.g. "9:00 - 10:15" -> "09:00-10:15")
+pub(crate) fn normalize_time_range(time: &str) -> String {
+    let normalize = |time: &str| match time.trim().split_once(':') {
+        Some((hour, minute)) => match (hour.trim().parse::<u32>(), minute.trim().parse::<u32>()) {
+            (Ok(hour), Ok(minute)) => format!("{hour:02}:{minute:02}"),
+            _ => time.trim().to_string(),
+        },
+        None => time.trim().to_string(),
+    };
+    match time.split_once(['-', '~']) {
+        Some((start, end)) => format!("{}-{}", normalize(start), normalize(end)),
+        None => time.trim().to_string(),
+    }
+}
+
+/// 강의시간(강의실) 표기에서 해석한 하나의 수업 시간
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ScheduleSlot {
+    pub(crate) weekday: Weekday,
+    pub(crate) time: String,
+    pub(crate) classroom: String,
+}
+
+/// 강의시간(강의실) 표기를 요일별 수업 시간으로 해석합니다. 강의실 뒤에 붙은 교수명은 제거합니다.
+pub(crate) fn parse_schedule_room(schedule_room: &str, professor: &str) -> Vec<ScheduleSlot> {
+    let professor = professor.trim();
+    SCHEDULE_ROOM
+        .captures_iter(schedule_room)
+        .flat_map(|captures| {
+            let time = normalize_time_range(&format!("{}-{}", &captures[2], &captures[3]));
+            let room = captures.get(4).map_or("", |room| room.as_str()).trim();
+            let classroom = if professor.is_empty() {
+                room
+            } else {
+                room.strip_suffix(professor).map_or(room, |room| {
+                    room.trim_end().trim_end_matches('-').trim_end()
+                })
+            }
+            .to_string();
+            captures[1]
+                .chars()
+                .filter_map(|c| Weekday::from_korean(&c.to_string()))
+                .map(|weekday| ScheduleSlot {
+                    weekday,
+                    time: time.clone(),
+                    classroom: classroom.clone(),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+impl PersonalCourseSchedule {
+    /// 수강신청 내역의 강의시간(강의실)으로 요일별 시간표를 만듭니다.
+    ///
+    /// 각 수업 정보에는 수강신청 내역의 과목번호가 포함되며, 강의시간이 없는 과목(e.g. 온라인 강의)은 제외됩니다.
+    pub fn from_registered_lectures<'a>(
+        lectures: impl IntoIterator<Item = &'a RegisteredLecture>,
+    ) -> PersonalCourseSchedule {
+        let mut schedule: HashMap<Weekday, Vec<CourseScheduleInformation>> = HashMap::new();
+        for lecture in lectures {
+            for slot in parse_schedule_room(&lecture.schedule_room, &lecture.professor) {
+                schedule
+                    .entry(slot.weekday)
+                    .or_default()
+                    .push(CourseScheduleInformation {
+                        division: lecture.division.clone(),
+                        ..CourseScheduleInformation::new(
+                            lecture.name.clone(),
+                            lecture.professor.clone(),
+                            slot.time,
+                            slot.classroom,
+                            Some(lecture.code.clone()),
+                        )
+                    });
+            }
+        }
+        for classes in schedule.values_mut() {
+            classes.sort_by(|a, b| a.time.cmp(&b.time));
+        }
+        PersonalCourseSchedule::new(schedule)
+    }
+
+    /// 수강신청 내역과 과목명, 수업 시간을 비교하여 각 수업에 과목번호와 분반을 연결합니다.
+    ///
+    /// 같은 이름의 과목이 여러 개라면 수업 요일과 시간이 일치하는 과목을 우선합니다. 연결할 과목을 찾지 못한 수업은 그대로 둡니다.
+    pub fn link_registered_lectures(&mut self, lectures: &[RegisteredLecture]) {
+        let slots: Vec<(&RegisteredLecture, Vec<ScheduleSlot>)> = lectures
+            .iter()
+            .map(|lecture| {
+                (
+                    lecture,
+                    parse_schedule_room(&lecture.schedule_room, &lecture.professor),
+                )
+            })
+            .collect();
+        for (weekday, classes) in self.schedule.iter_mut() {
+            for class in classes {
+                let name = normalize_name(&class.name);
+                let time = normalize_time_range(&class.time);
+                let mut candidates = slots
+                    .iter()
+                    .filter(|(lecture, _)| normalize_name(&lecture.name) == name);
+                let first = candidates.clone().next();
+                let matched = candidates
+                    .find(|(_, slots)| {
+                        slots
+                            .iter()
+                            .any(|slot| slot.weekday == *weekday && slot.time == time)
+                    })
+                    .or(first);
+                if let Some((lecture, _)) = matched {
+                    class.code = Some(lecture.code.clone());
+                    class.division = lecture.division.clone();
+                }
+            }
+        }
+    }
+}
+
+/// 비교를 위해 과목명의 공백을 제거합니다.
+pub(crate) fn normalize_name(name: &str) -> String {
+    name.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_schedule_room() {
+        let slots = parse_schedule_room(
+            "월 수 10:30-11:45 (정보과학관 21203-홍길동)\n금 09:00-10:15 (진리관 11101)",
+            "홍길동",
+        );
+        assert_eq!(
+            slots,
+            vec![
+                ScheduleSlot {
+                    weekday: Weekday::Mon,
+                    time: "10:30-11:45".to_string(),
+                    classroom: "정보과학관 21203".to_string(),
+                },
+                ScheduleSlot {
+                    weekday: Weekday::Wed,
+                    time: "10:30-11:45".to_string(),
+                    classroom: "정보과학관 21203".to_string(),
+                },
+                ScheduleSlot {
+                    weekday: Weekday::Fri,
+                    time: "09:00-10:15".to_string(),
+                    classroom: "진리관 11101".to_string(),
+                },
+            ]
+        );
+        assert!(parse_schedule_room("", "").is_empty());
+        assert_eq!(normalize_time_range("9:00 - 10:15"), "09:00-10:15");
+    }
+
+    #[test]
+    fn links_registered_lectures() {
+        let lecture = |code: &str, division: &str, schedule_room: &str| -> RegisteredLecture {
+            serde_json::from_value(serde_json::json!({
+                "syllabus": null, "category": "", "sub_category": null, "abeek_info": null,
+                "field": null, "code": code, "name": "데이터베이스", "division": division,
+                "professor": "", "time_points": "", "schedule_room": schedule_room,
+                "sm_objid": "", "se_objid": "", "full_name": "", "multi_major_info": "",
+                "program_code": "", "program_title": "", "registration_date": "",
+                "registration_time": "", "registered_at": null, "remark": "",
+            }))
+            .unwrap()
+        };
+        let lectures = vec![
+            lecture("2150001", "01", "월 09:00-10:15 (정보과학관 21203)"),
+            lecture("2150002", "02", "화 10:30-11:45 (정보과학관 21203)"),
+        ];
+        let mut schedule = PersonalCourseSchedule::new(HashMap::from([(
+            Weekday::Tue,
+            vec![CourseScheduleInformation::new(
+                "데이터베이스".to_string(),
+                "".to_string(),
+                "10:30-11:45".to_string(),
+                "정보과학관 21203".to_string(),
+                None,
+            )],
+        )]));
+        schedule.link_registered_lectures(&lectures);
+        let class = &schedule.schedule()[&Weekday::Tue][0];
+        assert_eq!(class.code(), Some(&"2150002".into()));
+        assert_eq!(class.division(), Some("02"));
+        let time = |h, m| chrono::NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        assert!(schedule.class_at(Weekday::Tue, time(11, 0)).is_some());
+        assert!(schedule.class_at(Weekday::Tue, time(11, 45)).is_none());
+        assert!(schedule.next_class(Weekday::Tue, time(9, 0)).is_some());
+    }
+}
@@ -0,0 +1,296 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    PersonalCourseScheduleApplication,
+    model::{PersonalCourseSchedule, Weekday, normalize_name, normalize_time_range},
+};
+use crate::{
+    RusaintError,
+    application::course_registration_status::{
+        CourseRegistrationStatusApplication, model::RegisteredLecture,
+    },
+    model::{LectureCode, SemesterType},
+};
+
+/// 개인수업시간표와 수강신청 내역의 불일치 종류
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ScheduleMismatchKind {
+    /// 수강신청 내역에는 있지만 시간표에는 없는 과목 (e.g. 수강신청 후 취소한 과목)
+    MissingFromSchedule,
+    /// 시간표에는 있지만 수강신청 내역에는 없는 과목
+    MissingFromRegistration,
+    /// 같은 과목의 수업 요일 또는 시간이 다름
+    TimeMismatch,
+    /// 같은 수업 시간의 강의실이 다름
+    ClassroomMismatch,
+}
+
+/// 개인수업시간표와 수강신청 내역의 불일치 항목
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ScheduleMismatch {
+    /// 불일치 종류
+    kind: ScheduleMismatchKind,
+    /// 과목명
+    name: String,
+    /// 과목번호 (수강신청 내역에 있는 과목인 경우)
+    code: Option<LectureCode>,
+    /// 수강신청 내역의 수업 시간 (e.g. "월 10:30-11:45 (정보과학관 21203)")
+    registered: Vec<String>,
+    /// 개인수업시간표의 수업 시간
+    scheduled: Vec<String>,
+}
+
+impl ScheduleMismatch {
+    /// 불일치 종류
+    pub fn kind(&self) -> ScheduleMismatchKind {
+        self.kind
+    }
+
+    /// 과목명
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 과목번호 (수강신청 내역에 있는 과목인 경우)
+    pub fn code(&self) -> Option<&LectureCode> {
+        self.code.as_ref()
+    }
+
+    /// 수강신청 내역의 수업 시간
+    pub fn registered(&self) -> &[String] {
+        &self.registered
+    }
+
+    /// 개인수업시간표의 수업 시간
+    pub fn scheduled(&self) -> &[String] {
+        &self.scheduled
+    }
+}
+
+/// 개인수업시간표와 수강신청 내역의 비교 결과
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ScheduleReconciliation {
+    /// 학년도
+    year: u32,
+    /// 학기
+    semester: SemesterType,
+    /// 수강신청 내역으로 만든 시간표
+    registered_schedule: PersonalCourseSchedule,
+    /// 불일치 항목
+    mismatches: Vec<ScheduleMismatch>,
+}
+
+/// 과목명별 (요일, 시간) -> 강의실
+type SlotMap = BTreeMap<String, BTreeMap<(Weekday, String), String>>;
+
+fn slots_by_name(schedule: &PersonalCourseSchedule) -> SlotMap {
+    let mut slots = SlotMap::new();
+    for (weekday, classes) in schedule.schedule() {
+        for class in classes {
+            slots
+                .entry(normalize_name(class.name()))
+                .or_default()
+                .insert(
+                    (*weekday, normalize_time_range(class.time())),
+                    class.classroom().trim().to_string(),
+                );
+        }
+    }
+    slots
+}
+
+fn describe(slots: &BTreeMap<(Weekday, String), String>) -> Vec<String> {
+    slots
+        .iter()
+        .map(|((weekday, time), classroom)| {
+            if classroom.is_empty() {
+                format!("{weekday} {time}")
+            } else {
+                format!("{weekday} {time} ({classroom})")
+            }
+        })
+        .collect()
+}
+
+impl ScheduleReconciliation {
+    /// 개인수업시간표와 수강신청 내역을 과목명 기준으로 비교합니다.
+    ///
+    /// 강의시간이 없는 수강신청 과목(e.g. 온라인 강의)은 시간표에 나타나지 않으므로 비교에서 제외합니다.
+    pub fn new(
+        year: u32,
+        semester: SemesterType,
+        schedule: &PersonalCourseSchedule,
+        lectures: &[RegisteredLecture],
+    ) -> ScheduleReconciliation {
+        let registered_schedule = PersonalCourseSchedule::from_registered_lectures(lectures);
+        let registered = slots_by_name(&registered_schedule);
+        let scheduled = slots_by_name(schedule);
+        let code_of = |name: &str| {
+            lectures
+                .iter()
+                .find(|lecture| normalize_name(&lecture.name) == name)
+                .map(|lecture| lecture.code.clone())
+        };
+        let display_name = |name: &str| {
+            lectures
+                .iter()
+                .find(|lecture| normalize_name(&lecture.name) == name)
+                .map(|lecture| lecture.name.clone())
+                .or_else(|| {
+                    schedule
+                        .schedule()
+                        .values()
+                        .flatten()
+                        .find(|class| normalize_name(class.name()) == name)
+                        .map(|class| class.name().to_string())
+                })
+                .unwrap_or_else(|| name.to_string())
+        };
+        let empty = BTreeMap::new();
+        let names: BTreeSet<&String> = registered.keys().chain(scheduled.keys()).collect();
+        let mut mismatches = Vec::new();
+        for name in names {
+            let registered_slots = registered.get(name).unwrap_or(&empty);
+            let scheduled_slots = scheduled.get(name).unwrap_or(&empty);
+            let kind =
+                if scheduled_slots.is_empty() {
+                    ScheduleMismatchKind::MissingFromSchedule
+                } else if registered_slots.is_empty() {
+                    ScheduleMismatchKind::MissingFromRegistration
+                } else if !registered_slots.keys().eq(scheduled_slots.keys()) {
+                    ScheduleMismatchKind::TimeMismatch
+                } else if registered_slots.iter().zip(scheduled_slots.iter()).any(
+                    |((_, a), (_, b))| {
+                        !a.is_empty() && !b.is_empty() && normalize_name(a) != normalize_name(b)
+                    },
+                ) {
+                    ScheduleMismatchKind::ClassroomMismatch
+                } else {
+                    continue;
+                };
+            mismatches.push(ScheduleMismatch {
+                kind,
+                name: display_name(name),
+                code: code_of(name),
+                registered: describe(registered_slots),
+                scheduled: describe(scheduled_slots),
+            });
+        }
+        ScheduleReconciliation {
+            year,
+            semester,
+            registered_schedule,
+            mismatches,
+        }
+    }
+
+    /// 학년도
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 학기
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 수강신청 내역으로 만든 시간표
+    pub fn registered_schedule(&self) -> &PersonalCourseSchedule {
+        &self.registered_schedule
+    }
+
+    /// 불일치 항목
+    pub fn mismatches(&self) -> &[ScheduleMismatch] {
+        &self.mismatches
+    }
+
+    /// 두 정보가 일치하는지 여부
+    pub fn is_consistent(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// 개인수업시간표와 수강신청 조회 애플리케이션을 함께 사용하여 과목번호와 분반이 연결된 해당 학기의 시간표를 가져옵니다.
+pub async fn linked_schedule(
+    schedule_app: &mut PersonalCourseScheduleApplication,
+    registration_app: &mut CourseRegistrationStatusApplication,
+    year: u32,
+    semester: SemesterType,
+) -> Result<PersonalCourseSchedule, RusaintError> {
+    let mut schedule = schedule_app.schedule(year, semester).await?;
+    let lectures: Vec<RegisteredLecture> =
+        registration_app.lectures(year, semester).await?.collect();
+    schedule.link_registered_lectures(&lectures);
+    Ok(schedule)
+}
+
+/// 개인수업시간표와 수강신청 조회 애플리케이션을 함께 사용하여 해당 학기의 시간표와 수강신청 내역을 비교합니다.
+pub async fn reconcile_schedule(
+    schedule_app: &mut PersonalCourseScheduleApplication,
+    registration_app: &mut CourseRegistrationStatusApplication,
+    year: u32,
+    semester: SemesterType,
+) -> Result<ScheduleReconciliation, RusaintError> {
+    let schedule = schedule_app.schedule(year, semester).await?;
+    let lectures: Vec<RegisteredLecture> =
+        registration_app.lectures(year, semester).await?.collect();
+    Ok(ScheduleReconciliation::new(
+        year, semester, &schedule, &lectures,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::application::personal_course_schedule::model::CourseScheduleInformation;
+
+    fn lecture(code: &str, name: &str, schedule_room: &str) -> RegisteredLecture {
+        serde_json::from_value(serde_json::json!({
+            "syllabus": null, "category": "", "sub_category": null, "abeek_info": null,
+            "field": null, "code": code, "name": name, "division": null, "professor": "홍길동",
+            "time_points": "", "schedule_room": schedule_room, "sm_objid": "", "se_objid": "",
+            "full_name": "", "multi_major_info": "", "program_code": "", "program_title": "",
+            "registration_date": "", "registration_time": "", "registered_at": null, "remark": "",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn detects_mismatches() {
+        let lectures = vec![
+            lecture(
+                "2150001",
+                "데이터베이스",
+                "월 10:30-11:45 (정보과학관 21203-홍길동)",
+            ),
+            lecture(
+                "2150002",
+                "운영체제",
+                "화 09:00-10:15 (정보과학관 21204-홍길동)",
+            ),
+        ];
+        let schedule = PersonalCourseSchedule::new(HashMap::from([(
+            Weekday::Mon,
+            vec![CourseScheduleInformation::new(
+                "데이터베이스".to_string(),
+                "홍길동".to_string(),
+                "10:30-11:45".to_string(),
+                "정보과학관 21203".to_string(),
+                None,
+            )],
+        )]));
+        let reconciliation =
+            ScheduleReconciliation::new(2025, SemesterType::One, &schedule, &lectures);
+        assert_eq!(reconciliation.mismatches().len(), 1);
+        let mismatch = &reconciliation.mismatches()[0];
+        assert_eq!(mismatch.kind(), ScheduleMismatchKind::MissingFromSchedule);
+        assert_eq!(mismatch.code(), Some(&"2150002".into()));
+    }
+}
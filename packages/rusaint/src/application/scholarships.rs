@@ -1,5 +1,5 @@
 use crate::RusaintError;
-use crate::application::scholarships::model::Scholarship;
+use crate::application::scholarships::model::{Scholarship, ScholarshipSummary};
 use crate::client::{USaintApplication, USaintClient};
 use wdpe::body::Body;
 use wdpe::element::parser::ElementParser;
@@ -32,6 +32,12 @@ impl ScholarshipsApplication {
         Scholarship::with_parser(&parser)
     }
 
+    /// 장학금 수혜 내역을 학기별, 장학금명별로 합산한 요약을 가져옵니다.
+    pub async fn summary(&mut self) -> Result<ScholarshipSummary, RusaintError> {
+        let scholarships = self.scholarships().await?;
+        Ok(ScholarshipSummary::new(&scholarships))
+    }
+
     /// 페이지를 새로고침합니다.
     pub async fn reload(&mut self) -> Result<(), RusaintError> {
         self.client.reload().await?;
@@ -41,3 +47,6 @@ impl ScholarshipsApplication {
 
 /// [`ScholarshipsApplication`] 애플리케이션에 사용되는 데이터
 pub mod model;
+
+/// [`ScholarshipsApplication`]의 장학금 데이터를 CSV, Markdown, HTML로 내보내기 위한 도구
+pub mod export;
@@ -0,0 +1,96 @@
+use super::model::{Scholarship, ScholarshipNameSummary, ScholarshipSemesterSummary};
+use crate::export::TableRow;
+
+impl TableRow for Scholarship {
+    fn headers() -> Vec<&'static str> {
+        vec![
+            "학년도",
+            "학기",
+            "장학금명",
+            "처리상태",
+            "지급방법",
+            "처리일자",
+            "선발금액",
+            "실수혜금액",
+            "환수금액",
+            "교체금액",
+            "교체장학금명",
+            "탈락사유",
+            "비고",
+        ]
+    }
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.year().to_string(),
+            self.semester().to_string(),
+            self.name().to_string(),
+            self.status().to_string(),
+            self.receive_type().to_string(),
+            self.processed_at()
+                .map(|date| date.to_string())
+                .unwrap_or_default(),
+            self.selected_amount().to_string(),
+            self.received_amount().to_string(),
+            self.refunded_amount().to_string(),
+            self.replaced_amount().to_string(),
+            self.replaced_by().to_string(),
+            self.drop_reason().to_string(),
+            self.note().to_string(),
+        ]
+    }
+}
+
+impl TableRow for ScholarshipSemesterSummary {
+    fn headers() -> Vec<&'static str> {
+        vec![
+            "학년도",
+            "학기",
+            "장학금명",
+            "건수",
+            "선발금액",
+            "실수혜금액",
+            "환수금액",
+            "교체금액",
+        ]
+    }
+
+    fn row(&self) -> Vec<String> {
+        let amounts = self.amounts();
+        vec![
+            self.year().to_string(),
+            self.semester().to_string(),
+            self.names().join(", "),
+            amounts.count().to_string(),
+            amounts.selected().to_string(),
+            amounts.received().to_string(),
+            amounts.refunded().to_string(),
+            amounts.replaced().to_string(),
+        ]
+    }
+}
+
+impl TableRow for ScholarshipNameSummary {
+    fn headers() -> Vec<&'static str> {
+        vec![
+            "장학금명",
+            "건수",
+            "선발금액",
+            "실수혜금액",
+            "환수금액",
+            "교체금액",
+        ]
+    }
+
+    fn row(&self) -> Vec<String> {
+        let amounts = self.amounts();
+        vec![
+            self.name().to_string(),
+            amounts.count().to_string(),
+            amounts.selected().to_string(),
+            amounts.received().to_string(),
+            amounts.refunded().to_string(),
+            amounts.replaced().to_string(),
+        ]
+    }
+}
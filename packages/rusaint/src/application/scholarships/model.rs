@@ -1,6 +1,8 @@
 use crate::application::utils::de_with::deserialize_comma_u64_string;
+use crate::application::utils::de_with::deserialize_optional_date;
 use crate::application::utils::de_with::deserialize_semester_type;
-use std::collections::HashMap;
+use chrono::NaiveDate;
+use std::{collections::HashMap, convert::Infallible, fmt::Display, str::FromStr};
 
 use serde::{
     Deserialize, Serialize,
@@ -28,6 +30,12 @@ use wdpe::{
     error::{ElementError, WebDynproError},
 };
 
+mod summary;
+
+pub use summary::{
+    ScholarshipAmounts, ScholarshipNameSummary, ScholarshipSemesterSummary, ScholarshipSummary,
+};
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 /// 수혜받은 장학금 정보
@@ -50,11 +58,14 @@ pub struct Scholarship {
     )]
     received_amount: u64,
     #[serde(rename(deserialize = "지급방법"))]
-    receive_type: String,
+    receive_type: ScholarshipReceiveType,
     #[serde(rename(deserialize = "처리상태"))]
-    status: String,
-    #[serde(rename(deserialize = "처리일자"))]
-    processed_at: String,
+    status: ScholarshipStatus,
+    #[serde(
+        rename(deserialize = "처리일자"),
+        deserialize_with = "deserialize_optional_date"
+    )]
+    processed_at: Option<NaiveDate>,
     #[serde(
         rename(deserialize = "선발금액"),
         deserialize_with = "deserialize_comma_u64_string"
@@ -120,18 +131,18 @@ impl Scholarship {
     }
 
     /// 지급방법을 반환합니다.
-    pub fn receive_type(&self) -> &str {
+    pub fn receive_type(&self) -> &ScholarshipReceiveType {
         &self.receive_type
     }
 
     /// 처리상태를 반환합니다.
-    pub fn status(&self) -> &str {
+    pub fn status(&self) -> &ScholarshipStatus {
         &self.status
     }
 
-    /// 처리일자를 반환합니다.
-    pub fn processed_at(&self) -> &str {
-        &self.processed_at
+    /// 처리일자를 반환합니다. 아직 처리되지 않은 경우 `None`을 반환합니다.
+    pub fn processed_at(&self) -> Option<NaiveDate> {
+        self.processed_at
     }
 
     /// 선발금액을 반환합니다.
@@ -186,3 +197,124 @@ impl<'body> FromSapTable<'body> for Scholarship {
         )
     }
 }
+
+/// 장학금 처리상태
+///
+/// 문자열로 직렬화되며, 알 수 없는 상태는 [`ScholarshipStatus::Unknown`]으로 보존됩니다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", from = "String")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ScholarshipStatus {
+    /// 선발
+    Selected,
+    /// 지급
+    Paid,
+    /// 탈락
+    Dropped,
+    /// 환수
+    Refunded,
+    /// 취소
+    Cancelled,
+    /// 알 수 없는 상태
+    Unknown(String),
+}
+
+impl ScholarshipStatus {
+    /// 장학금을 실제로 받은 상태인지 여부
+    pub fn is_paid(&self) -> bool {
+        *self == Self::Paid
+    }
+}
+
+impl FromStr for ScholarshipStatus {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "선발" | "선발완료" => Self::Selected,
+            "지급" | "지급완료" => Self::Paid,
+            "탈락" => Self::Dropped,
+            "환수" | "환수완료" => Self::Refunded,
+            "취소" | "선발취소" => Self::Cancelled,
+            other => Self::Unknown(other.to_string()),
+        })
+    }
+}
+
+impl Display for ScholarshipStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Self::Selected => "선발",
+            Self::Paid => "지급",
+            Self::Dropped => "탈락",
+            Self::Refunded => "환수",
+            Self::Cancelled => "취소",
+            Self::Unknown(status) => status,
+        };
+        write!(f, "{str}")
+    }
+}
+
+impl From<String> for ScholarshipStatus {
+    fn from(value: String) -> Self {
+        let Ok(status) = value.parse();
+        status
+    }
+}
+
+impl From<ScholarshipStatus> for String {
+    fn from(value: ScholarshipStatus) -> Self {
+        value.to_string()
+    }
+}
+
+/// 장학금 지급방법
+///
+/// 문자열로 직렬화되며, 알 수 없는 지급방법은 [`ScholarshipReceiveType::Unknown`]으로 보존됩니다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", from = "String")]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ScholarshipReceiveType {
+    /// 등록금 감면
+    TuitionReduction,
+    /// 현금 지급
+    Cash,
+    /// 알 수 없는 지급방법
+    Unknown(String),
+}
+
+impl FromStr for ScholarshipReceiveType {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "등록금감면" | "등록금 감면" | "감면" => Self::TuitionReduction,
+            "현금지급" | "현금 지급" | "현금" | "계좌입금" => Self::Cash,
+            other => Self::Unknown(other.to_string()),
+        })
+    }
+}
+
+impl Display for ScholarshipReceiveType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Self::TuitionReduction => "등록금감면",
+            Self::Cash => "현금지급",
+            Self::Unknown(receive_type) => receive_type,
+        };
+        write!(f, "{str}")
+    }
+}
+
+impl From<String> for ScholarshipReceiveType {
+    fn from(value: String) -> Self {
+        let Ok(receive_type) = value.parse();
+        receive_type
+    }
+}
+
+impl From<ScholarshipReceiveType> for String {
+    fn from(value: ScholarshipReceiveType) -> Self {
+        value.to_string()
+    }
+}
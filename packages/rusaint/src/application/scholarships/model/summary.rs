@@ -0,0 +1,250 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::Scholarship;
+use crate::model::SemesterType;
+
+/// 장학금 금액 합계
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ScholarshipAmounts {
+    /// 장학금 건수
+    count: u32,
+    /// 선발금액 합계
+    selected: u64,
+    /// 실수혜금액 합계
+    received: u64,
+    /// 환수금액 합계
+    refunded: u64,
+    /// 교체금액 합계
+    replaced: u64,
+}
+
+impl ScholarshipAmounts {
+    fn add(&mut self, scholarship: &Scholarship) {
+        self.count += 1;
+        self.selected += scholarship.selected_amount();
+        self.received += scholarship.received_amount();
+        self.refunded += scholarship.refunded_amount();
+        self.replaced += scholarship.replaced_amount();
+    }
+
+    /// 장학금 건수
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// 선발금액 합계
+    pub fn selected(&self) -> u64 {
+        self.selected
+    }
+
+    /// 실수혜금액 합계
+    pub fn received(&self) -> u64 {
+        self.received
+    }
+
+    /// 환수금액 합계
+    pub fn refunded(&self) -> u64 {
+        self.refunded
+    }
+
+    /// 교체금액 합계
+    pub fn replaced(&self) -> u64 {
+        self.replaced
+    }
+}
+
+/// 학기별 장학금 합계
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ScholarshipSemesterSummary {
+    /// 학년도
+    year: u32,
+    /// 학기
+    semester: SemesterType,
+    /// 장학금명 (처음 나타난 순서)
+    names: Vec<String>,
+    /// 금액 합계
+    amounts: ScholarshipAmounts,
+}
+
+impl ScholarshipSemesterSummary {
+    /// 학년도
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 학기
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 장학금명 (처음 나타난 순서)
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// 금액 합계
+    pub fn amounts(&self) -> &ScholarshipAmounts {
+        &self.amounts
+    }
+}
+
+/// 장학금명별 합계
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ScholarshipNameSummary {
+    /// 장학금명
+    name: String,
+    /// 금액 합계
+    amounts: ScholarshipAmounts,
+}
+
+impl ScholarshipNameSummary {
+    /// 장학금명
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 금액 합계
+    pub fn amounts(&self) -> &ScholarshipAmounts {
+        &self.amounts
+    }
+}
+
+/// 장학금 수혜 내역 요약
+///
+/// 금액은 처리상태와 관계없이 수혜 내역에 기록된 그대로 합산합니다.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ScholarshipSummary {
+    /// 전체 합계
+    total: ScholarshipAmounts,
+    /// 학기별 합계 (오래된 학기부터)
+    semesters: Vec<ScholarshipSemesterSummary>,
+    /// 장학금명별 합계 (실수혜금액이 많은 순)
+    scholarships: Vec<ScholarshipNameSummary>,
+}
+
+impl ScholarshipSummary {
+    /// 장학금 수혜 내역으로 요약을 만듭니다.
+    pub fn new<'a>(scholarships: impl IntoIterator<Item = &'a Scholarship>) -> Self {
+        let mut total = ScholarshipAmounts::default();
+        let mut by_semester: BTreeMap<(u32, SemesterType), (Vec<String>, ScholarshipAmounts)> =
+            BTreeMap::new();
+        let mut by_name: BTreeMap<&str, ScholarshipAmounts> = BTreeMap::new();
+        for scholarship in scholarships {
+            total.add(scholarship);
+            let (names, amounts) = by_semester
+                .entry((scholarship.year(), scholarship.semester()))
+                .or_default();
+            if !names.iter().any(|name| name == scholarship.name()) {
+                names.push(scholarship.name().to_string());
+            }
+            amounts.add(scholarship);
+            by_name
+                .entry(scholarship.name())
+                .or_default()
+                .add(scholarship);
+        }
+        let semesters = by_semester
+            .into_iter()
+            .map(
+                |((year, semester), (names, amounts))| ScholarshipSemesterSummary {
+                    year,
+                    semester,
+                    names,
+                    amounts,
+                },
+            )
+            .collect();
+        let mut scholarships: Vec<ScholarshipNameSummary> = by_name
+            .into_iter()
+            .map(|(name, amounts)| ScholarshipNameSummary {
+                name: name.to_string(),
+                amounts,
+            })
+            .collect();
+        scholarships.sort_by_key(|summary| std::cmp::Reverse(summary.amounts.received));
+        Self {
+            total,
+            semesters,
+            scholarships,
+        }
+    }
+
+    /// 전체 합계
+    pub fn total(&self) -> &ScholarshipAmounts {
+        &self.total
+    }
+
+    /// 학기별 합계 (오래된 학기부터)
+    pub fn semesters(&self) -> &[ScholarshipSemesterSummary] {
+        &self.semesters
+    }
+
+    /// 장학금명별 합계 (실수혜금액이 많은 순)
+    pub fn scholarships(&self) -> &[ScholarshipNameSummary] {
+        &self.scholarships
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::scholarships::model::{ScholarshipReceiveType, ScholarshipStatus};
+
+    fn scholarship(
+        year: &str,
+        semester: &str,
+        name: &str,
+        status: &str,
+        received: &str,
+        refunded: &str,
+    ) -> Scholarship {
+        serde_json::from_value(serde_json::json!({
+            "학년": year, "학기": semester, "장학금명": name, "실수혜금액": received,
+            "지급방법": "등록금감면", "처리상태": status, "처리일자": "2024.03.05",
+            "선발금액": "1,000,000", "환수금액": refunded, "교체금액": "0",
+            "교체장학금명": "", "탈락사유": "", "비고": "", "근로부서": "",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn summarizes_by_semester_and_name() {
+        let scholarships = vec![
+            scholarship("2024", "1 학기", "성적우수장학금", "지급", "1,000,000", "0"),
+            scholarship("2024", "1 학기", "근로장학금", "지급완료", "500,000", "0"),
+            scholarship("2023", "2 학기", "성적우수장학금", "환수", "0", "1,000,000"),
+        ];
+        assert_eq!(scholarships[0].status(), &ScholarshipStatus::Paid);
+        assert_eq!(
+            scholarships[0].receive_type(),
+            &ScholarshipReceiveType::TuitionReduction
+        );
+        assert_eq!(
+            scholarships[0].processed_at(),
+            chrono::NaiveDate::from_ymd_opt(2024, 3, 5)
+        );
+        let summary = ScholarshipSummary::new(&scholarships);
+        assert_eq!(summary.total().count(), 3);
+        assert_eq!(summary.total().selected(), 3_000_000);
+        assert_eq!(summary.total().received(), 1_500_000);
+        assert_eq!(summary.total().refunded(), 1_000_000);
+        assert_eq!(summary.semesters()[0].year(), 2023);
+        assert_eq!(summary.semesters()[1].names().len(), 2);
+        assert_eq!(summary.semesters()[1].amounts().received(), 1_500_000);
+        assert_eq!(summary.scholarships()[0].name(), "성적우수장학금");
+        assert_eq!(summary.scholarships()[0].amounts().count(), 2);
+    }
+
+    #[test]
+    fn preserves_unknown_status() {
+        let status: ScholarshipStatus = "심사중".to_string().into();
+        assert_eq!(status, ScholarshipStatus::Unknown("심사중".to_string()));
+        assert_eq!(String::from(status), "심사중");
+    }
+}
@@ -1,7 +1,7 @@
 use model::{
-    StudentAcademicRecords, StudentBankAccount, StudentFamily, StudentGraduation,
-    StudentInformation, StudentQualification, StudentReligion, StudentResearchBankAccount,
-    StudentTransferRecords, StudentWorkInformation,
+    ContactUpdate, ContactUpdateResult, StudentAcademicRecords, StudentBankAccount, StudentFamily,
+    StudentGraduation, StudentInformation, StudentPhoto, StudentProfile, StudentQualification,
+    StudentReligion, StudentResearchBankAccount, StudentTransferRecords, StudentWorkInformation,
 };
 
 use crate::RusaintError;
@@ -40,6 +40,24 @@ impl<'a> StudentInformationApplication {
         ))?)
     }
 
+    /// 학생 사진을 가져옵니다.
+    pub async fn photo(&self) -> Result<StudentPhoto, RusaintError> {
+        StudentPhoto::with_client(&self.client).await
+    }
+
+    /// 학생의 연락처(이메일, 전화번호, 주소)를 수정하고 저장합니다.
+    ///
+    /// 저장 전에 수정값의 형식을 검증하며, 현재 값과 같은 항목은 전송하지 않습니다.
+    /// `dry_run`이 참이면 서버에 이벤트를 전송하지 않고 전송할 이벤트 목록만 반환합니다.
+    /// 저장 후(또는 저장에 실패한 경우) 페이지를 새로고침하며, 서버가 오류 메시지를 표시했다면 오류를 반환합니다.
+    pub async fn update_contact(
+        &mut self,
+        update: &ContactUpdate,
+        dry_run: bool,
+    ) -> Result<ContactUpdateResult, RusaintError> {
+        update.apply(&mut self.client, dry_run).await
+    }
+
     /// 학생의 졸업과 관련된 정보를 반환합니다.
     pub fn graduation(&self) -> Result<StudentGraduation, RusaintError> {
         Ok(StudentGraduation::with_parser(&ElementParser::new(
@@ -91,6 +109,13 @@ impl<'a> StudentInformationApplication {
         Ok(StudentResearchBankAccount::with_client(&mut self.client).await?)
     }
 
+    /// 모든 탭의 학생 정보를 모아 프로필로 반환합니다.
+    ///
+    /// 일부 탭을 가져오지 못하더라도 중단하지 않고, 실패한 항목과 그 이유를 [`StudentProfile::errors`]에 기록합니다.
+    pub async fn profile(&mut self) -> StudentProfile {
+        StudentProfile::with_application(self).await
+    }
+
     /// 페이지를 새로고침합니다.
     pub async fn reload(&mut self) -> Result<(), RusaintError> {
         self.client.reload().await?;
@@ -1,8 +1,6 @@
 use serde::{Deserialize, Serialize};
 use wdpe::{
-    command::element::text::InputFieldValueCommand,
-    define_elements,
-    element::{action::Button, graphic::Image, text::InputField},
+    command::element::text::InputFieldValueCommand, define_elements, element::text::InputField,
     error::WebDynproError,
 };
 
@@ -13,15 +11,13 @@ pub struct StudentInformation {
     apply_year: u32,
     student_number: u32,
     name: String,
-    rrn: u32,
+    rrn: String,
     collage: String,
     department: String,
     major: Option<String>,
     division: Option<String>,
     grade: u32,
     term: u32,
-    #[allow(unused)]
-    image: Vec<u8>,
     alias: Option<String>,
     kanji_name: Option<String>,
     email: Option<String>,
@@ -62,9 +58,6 @@ impl<'a> StudentInformation {
         CMSTYEAR: InputField<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.CMSTYEAR";
         // 학기
         ZSCHTERM: InputField<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.ZSCHTERM";
-        // 사진
-        #[allow(unused)]
-        ST_IMAGE: Image<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.ST_IMAGE";
     }
 
     define_elements! {
@@ -98,10 +91,6 @@ impl<'a> StudentInformation {
         COLEG_CDT: InputField<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.TC_DEFAULT_COLEG_CDT";
         // (입학)학부
         DEPT_CDT: InputField<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.TC_DEFAULT_DEPT_CDT";
-        #[allow(unused)]
-        MODIFY_BUTTON: Button<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.MODIFY_BUTTON";
-        #[allow(unused)]
-        SAVE_BUTTON: Button<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.SAVE_BUTTON";
     }
 
     define_elements! {
@@ -126,7 +115,7 @@ impl<'a> StudentInformation {
                 .element_from_def(&Self::STUDENT12)?
                 .value_into_u32()?,
             name: parser.read(InputFieldValueCommand::new(Self::VORNA))?,
-            rrn: parser.element_from_def(&Self::PRDNI)?.value_into_u32()?,
+            rrn: parser.read(InputFieldValueCommand::new(Self::PRDNI))?,
             collage: parser.read(InputFieldValueCommand::new(Self::COLEG_TXT))?,
             department: parser.read(InputFieldValueCommand::new(Self::DEPT_TXT))?,
             major: parser
@@ -135,7 +124,6 @@ impl<'a> StudentInformation {
             division: parser.read(InputFieldValueCommand::new(Self::TITEL)).ok(),
             grade: parser.element_from_def(&Self::CMSTYEAR)?.value_into_u32()?,
             term: parser.element_from_def(&Self::ZSCHTERM)?.value_into_u32()?,
-            image: Vec::with_capacity(0), // TODO: Image to bytes
             alias: parser.read(InputFieldValueCommand::new(Self::RUFNM)).ok(),
             kanji_name: parser
                 .read(InputFieldValueCommand::new(Self::BIRTHNAME))
@@ -191,8 +179,8 @@ impl<'a> StudentInformation {
     }
 
     /// 주민번호(앞자리)를 반환합니다.
-    pub fn rrn(&self) -> u32 {
-        self.rrn
+    pub fn rrn(&self) -> &str {
+        &self.rrn
     }
 
     /// 대학(원)을 반환합니다.
@@ -225,11 +213,6 @@ impl<'a> StudentInformation {
         self.term
     }
 
-    // 사진의 바이너리를 반환합니다.
-    // pub fn image(&self) -> &[u8] {
-    //     &self.image
-    // }
-
     /// 통칭 이름을 반환합니다.
     pub fn alias(&self) -> Option<&str> {
         self.alias.as_deref()
@@ -305,10 +288,29 @@ impl<'a> StudentInformation {
         self.abeek.as_deref()
     }
 }
+
+impl Redact for StudentInformation {
+    fn redacted(&self) -> Self {
+        Self {
+            rrn: mask_all(&self.rrn),
+            email: self.email.as_deref().map(mask_email),
+            tel_number: self.tel_number.as_deref().map(mask_phone),
+            mobile_number: self.mobile_number.as_deref().map(mask_phone),
+            post_code: self.post_code.as_deref().map(mask_all),
+            address: self.address.as_deref().map(mask_address),
+            specific_address: self.specific_address.as_deref().map(mask_all),
+            ..self.clone()
+        }
+    }
+}
+
 mod academic_record;
 mod bank_account;
+mod contact;
 mod family;
 mod graduation;
+mod photo;
+mod profile;
 mod qualification;
 mod religion;
 mod research_bank_account;
@@ -317,8 +319,13 @@ mod work;
 
 pub use academic_record::{StudentAcademicRecord, StudentAcademicRecords};
 pub use bank_account::StudentBankAccount;
+pub use contact::{
+    ContactField, ContactUpdate, ContactUpdateEvent, ContactUpdateResult, ContactValidationError,
+};
 pub use family::{StudentFamily, StudentFamilyMember};
 pub use graduation::StudentGraduation;
+pub use photo::StudentPhoto;
+pub use profile::{StudentProfile, StudentProfileError, StudentProfileSection};
 pub use qualification::{
     StudentForignStudyInformation, StudentLifelongInformation, StudentQualification,
     StudentTeachingMajorInformation, StudentTeachingPluralMajorInformation,
@@ -331,3 +338,4 @@ use wdpe::element::parser::ElementParser;
 pub use work::StudentWorkInformation;
 
 use crate::application::utils::input_field::InputFieldExt as _;
+use crate::redact::{Redact, mask_address, mask_all, mask_email, mask_phone};
@@ -1,5 +1,6 @@
 use crate::application::student_information::StudentInformationApplication;
 use crate::client::USaintClient;
+use crate::redact::{Redact, mask_digits, mask_name};
 
 use serde::{Deserialize, Serialize};
 use wdpe::command::WebDynproCommandExecutor;
@@ -77,3 +78,16 @@ impl<'a> StudentBankAccount {
         self.holder.as_deref()
     }
 }
+
+impl Redact for StudentBankAccount {
+    fn redacted(&self) -> Self {
+        Self {
+            bank: self.bank.clone(),
+            account_number: self
+                .account_number
+                .as_deref()
+                .map(|account_number| mask_digits(account_number, 4)),
+            holder: self.holder.as_deref().map(mask_name),
+        }
+    }
+}
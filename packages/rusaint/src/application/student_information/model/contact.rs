@@ -0,0 +1,644 @@
+use std::{collections::HashMap, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use wdpe::{
+    command::{
+        WebDynproCommandExecutor,
+        element::{action::ButtonPressEventCommand, text::InputFieldValueCommand},
+    },
+    define_elements,
+    element::{
+        Element, ElementWrapper, Interactable, action::Button, definition::ElementDefinition,
+        parser::ElementParser, text::InputField,
+    },
+    scraper::{ElementRef, Html, Selector},
+};
+
+use super::StudentInformation;
+use crate::{
+    ApplicationError, RusaintError,
+    application::utils::popup::close_popups,
+    client::USaintClient,
+    redact::{Redact, mask_address, mask_all, mask_email, mask_phone},
+};
+
+/// 수정할 수 있는 학생 연락처 항목
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ContactField {
+    /// 이메일
+    Email,
+    /// 집전화
+    TelNumber,
+    /// 휴대전화
+    MobileNumber,
+    /// 우편번호
+    PostCode,
+    /// 주소1
+    Address,
+    /// 주소2
+    SpecificAddress,
+}
+
+impl Display for ContactField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Self::Email => "이메일",
+            Self::TelNumber => "집전화",
+            Self::MobileNumber => "휴대전화",
+            Self::PostCode => "우편번호",
+            Self::Address => "주소1",
+            Self::SpecificAddress => "주소2",
+        };
+        write!(f, "{str}")
+    }
+}
+
+impl ContactField {
+    fn mask(self, value: &str) -> String {
+        match self {
+            Self::Email => mask_email(value),
+            Self::TelNumber | Self::MobileNumber => mask_phone(value),
+            Self::Address => mask_address(value),
+            Self::PostCode | Self::SpecificAddress => mask_all(value),
+        }
+    }
+}
+
+/// 연락처 수정값이 올바르지 않을 때 반환하는 오류
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ContactValidationError {
+    /// 수정할 항목이 없음
+    #[error("No contact field to update")]
+    NoChanges,
+    /// 올바르지 않은 이메일 주소
+    #[error("Invalid email address: {0:?}")]
+    InvalidEmail(String),
+    /// 올바르지 않은 전화번호
+    #[error("Invalid phone number for {field}: {value:?}")]
+    InvalidPhoneNumber {
+        /// 전화번호 항목
+        field: ContactField,
+        /// 입력한 값
+        value: String,
+    },
+    /// 올바르지 않은 우편번호
+    #[error("Invalid post code: {0:?}")]
+    InvalidPostCode(String),
+    /// 비어 있거나 너무 긴 주소
+    #[error("Invalid address for {field}: {value:?}")]
+    InvalidAddress {
+        /// 주소 항목
+        field: ContactField,
+        /// 입력한 값
+        value: String,
+    },
+}
+
+/// 학생 연락처 수정 요청. `None`인 항목은 수정하지 않습니다.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ContactUpdate {
+    /// 이메일
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    pub email: Option<String>,
+    /// 집전화
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    pub tel_number: Option<String>,
+    /// 휴대전화
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    pub mobile_number: Option<String>,
+    /// 우편번호
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    pub post_code: Option<String>,
+    /// 주소1
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    pub address: Option<String>,
+    /// 주소2
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    pub specific_address: Option<String>,
+}
+
+const MAX_ADDRESS_LENGTH: usize = 100;
+
+fn is_valid_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !email.chars().any(char::is_whitespace)
+}
+
+fn is_valid_phone_number(number: &str, mobile: bool) -> bool {
+    if !number.chars().all(|c| c.is_ascii_digit() || c == '-') {
+        return false;
+    }
+    let digits: String = number.chars().filter(char::is_ascii_digit).collect();
+    (9..=11).contains(&digits.len())
+        && digits.starts_with('0')
+        && (!mobile || digits.starts_with("01"))
+}
+
+fn is_valid_post_code(post_code: &str) -> bool {
+    let is_digits = |s: &str, len: usize| s.len() == len && s.chars().all(|c| c.is_ascii_digit());
+    match post_code.split_once('-') {
+        // 구 우편번호 (e.g. "156-743")
+        Some((front, back)) => is_digits(front, 3) && is_digits(back, 3),
+        None => is_digits(post_code, 5) || is_digits(post_code, 6),
+    }
+}
+
+impl ContactUpdate {
+    /// 수정할 항목과 값을 입력 순서대로 반환합니다. 값의 앞뒤 공백은 제거됩니다.
+    pub fn changes(&self) -> Vec<(ContactField, &str)> {
+        [
+            (ContactField::Email, &self.email),
+            (ContactField::TelNumber, &self.tel_number),
+            (ContactField::MobileNumber, &self.mobile_number),
+            (ContactField::PostCode, &self.post_code),
+            (ContactField::Address, &self.address),
+            (ContactField::SpecificAddress, &self.specific_address),
+        ]
+        .into_iter()
+        .filter_map(|(field, value)| value.as_deref().map(|value| (field, value.trim())))
+        .collect()
+    }
+
+    /// 수정값이 u-saint에 저장할 수 있는 형식인지 확인합니다.
+    pub fn validate(&self) -> Result<(), ContactValidationError> {
+        let changes = self.changes();
+        if changes.is_empty() {
+            return Err(ContactValidationError::NoChanges);
+        }
+        for (field, value) in changes {
+            let valid = match field {
+                ContactField::Email => is_valid_email(value),
+                ContactField::TelNumber => is_valid_phone_number(value, false),
+                ContactField::MobileNumber => is_valid_phone_number(value, true),
+                ContactField::PostCode => is_valid_post_code(value),
+                ContactField::Address | ContactField::SpecificAddress => {
+                    !value.is_empty()
+                        && value.chars().count() <= MAX_ADDRESS_LENGTH
+                        && !value.chars().any(char::is_control)
+                }
+            };
+            if valid {
+                continue;
+            }
+            let value = value.to_string();
+            return Err(match field {
+                ContactField::Email => ContactValidationError::InvalidEmail(value),
+                ContactField::TelNumber | ContactField::MobileNumber => {
+                    ContactValidationError::InvalidPhoneNumber { field, value }
+                }
+                ContactField::PostCode => ContactValidationError::InvalidPostCode(value),
+                ContactField::Address | ContactField::SpecificAddress => {
+                    ContactValidationError::InvalidAddress { field, value }
+                }
+            });
+        }
+        Ok(())
+    }
+}
+
+/// 연락처 수정 시 전송하는(또는 전송할) 이벤트
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ContactUpdateEvent {
+    /// 이벤트를 발생시키는 엘리먼트의 Id
+    element_id: String,
+    /// 이벤트 이름 (e.g. "Press", "Change")
+    event: String,
+    /// 수정하는 연락처 항목 (버튼 이벤트는 `None`)
+    field: Option<ContactField>,
+    /// 입력하는 값 (버튼 이벤트는 `None`)
+    value: Option<String>,
+}
+
+impl ContactUpdateEvent {
+    fn press(element_id: &str) -> Self {
+        Self {
+            element_id: element_id.to_string(),
+            event: "Press".to_string(),
+            field: None,
+            value: None,
+        }
+    }
+
+    fn change(element_id: &str, field: ContactField, value: &str) -> Self {
+        Self {
+            element_id: element_id.to_string(),
+            event: "Change".to_string(),
+            field: Some(field),
+            value: Some(value.to_string()),
+        }
+    }
+
+    /// 이벤트를 발생시키는 엘리먼트의 Id
+    pub fn element_id(&self) -> &str {
+        &self.element_id
+    }
+
+    /// 이벤트 이름
+    pub fn event(&self) -> &str {
+        &self.event
+    }
+
+    /// 수정하는 연락처 항목
+    pub fn field(&self) -> Option<ContactField> {
+        self.field
+    }
+
+    /// 입력하는 값
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+}
+
+impl Redact for ContactUpdateEvent {
+    fn redacted(&self) -> Self {
+        Self {
+            value: self
+                .field
+                .zip(self.value.as_deref())
+                .map(|(field, value)| field.mask(value))
+                .or_else(|| self.value.clone()),
+            ..self.clone()
+        }
+    }
+}
+
+/// 연락처 수정 결과
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ContactUpdateResult {
+    /// 실제로 저장하지 않고 전송할 이벤트만 확인했는지 여부
+    dry_run: bool,
+    /// 전송한(또는 전송할) 이벤트 목록
+    events: Vec<ContactUpdateEvent>,
+    /// 저장 후 서버가 표시한 메시지
+    messages: Vec<String>,
+    /// 저장 후 페이지를 새로고침하여 다시 읽은 연락처가 요청한 값과 모두 일치하는지 여부 (`dry_run`인 경우 `false`)
+    confirmed: bool,
+}
+
+impl ContactUpdateResult {
+    /// 실제로 저장하지 않고 전송할 이벤트만 확인했는지 여부
+    pub fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// 전송한(또는 전송할) 이벤트 목록
+    pub fn events(&self) -> &[ContactUpdateEvent] {
+        &self.events
+    }
+
+    /// 저장 후 서버가 표시한 메시지
+    pub fn messages(&self) -> &[String] {
+        &self.messages
+    }
+
+    /// 저장 후 다시 읽은 연락처가 요청한 값과 모두 일치하는지 여부
+    pub fn confirmed(&self) -> bool {
+        self.confirmed
+    }
+}
+
+impl Redact for ContactUpdateResult {
+    fn redacted(&self) -> Self {
+        Self {
+            events: self.events.redacted(),
+            ..self.clone()
+        }
+    }
+}
+
+impl<'a> ContactUpdate {
+    define_elements! {
+        // 이메일
+        SMTP_ADDR: InputField<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.SMTP_ADDR";
+        // 집전화
+        TEL_NUMBER: InputField<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.TEL_NUMBER";
+        // 휴대전화
+        MOB_NUMBER: InputField<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.MOB_NUMBER";
+        // 우편번호
+        POST_CODE: InputField<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.POST_CODE";
+        // 주소1
+        CITY1: InputField<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.CITY1";
+        // 주소2
+        STREET: InputField<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.STREET";
+        // 수정
+        MODIFY_BUTTON: Button<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.MODIFY_BUTTON";
+        // 저장
+        SAVE_BUTTON: Button<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.SAVE_BUTTON";
+    }
+
+    fn element(field: ContactField) -> wdpe::element::text::InputFieldDef {
+        match field {
+            ContactField::Email => Self::SMTP_ADDR,
+            ContactField::TelNumber => Self::TEL_NUMBER,
+            ContactField::MobileNumber => Self::MOB_NUMBER,
+            ContactField::PostCode => Self::POST_CODE,
+            ContactField::Address => Self::CITY1,
+            ContactField::SpecificAddress => Self::STREET,
+        }
+    }
+
+    /// 현재 값과 다른 항목만 골라냅니다.
+    fn pending_changes(&self, parser: &ElementParser) -> Vec<(ContactField, String)> {
+        self.changes()
+            .into_iter()
+            .filter(|(field, value)| {
+                parser
+                    .read(InputFieldValueCommand::new(Self::element(*field)))
+                    .map(|current| current.trim() != *value)
+                    .unwrap_or(true)
+            })
+            .map(|(field, value)| (field, value.to_string()))
+            .collect()
+    }
+
+    pub(crate) async fn apply(
+        &self,
+        client: &mut USaintClient,
+        dry_run: bool,
+    ) -> Result<ContactUpdateResult, RusaintError> {
+        self.validate()
+            .map_err(|err| RusaintError::ApplicationError(err.into()))?;
+        let (changes, has_modify_button) = {
+            let parser = ElementParser::new(client.body());
+            (
+                self.pending_changes(&parser),
+                parser.element_from_def(&Self::MODIFY_BUTTON).is_ok(),
+            )
+        };
+        if changes.is_empty() {
+            // 이미 요청한 값과 같다면 저장할 필요가 없습니다.
+            return Ok(ContactUpdateResult {
+                dry_run,
+                events: Vec::new(),
+                messages: Vec::new(),
+                confirmed: !dry_run,
+            });
+        }
+        let mut events = Vec::with_capacity(changes.len() + 2);
+        if has_modify_button {
+            events.push(ContactUpdateEvent::press(Self::MODIFY_BUTTON.id()));
+        }
+        events.extend(changes.iter().map(|(field, value)| {
+            ContactUpdateEvent::change(Self::element(*field).id(), *field, value)
+        }));
+        events.push(ContactUpdateEvent::press(Self::SAVE_BUTTON.id()));
+        if dry_run {
+            return Ok(ContactUpdateResult {
+                dry_run,
+                events,
+                messages: Vec::new(),
+                confirmed: false,
+            });
+        }
+
+        let messages = match Self::save(client, &changes, has_modify_button).await {
+            Ok(messages) => messages,
+            Err(err) => {
+                // 수정 상태로 남지 않도록 페이지를 새로고침합니다.
+                let _ = client.reload().await;
+                return Err(err);
+            }
+        };
+        // 입력 필드에 남은 값이 아닌 서버에 저장된 값을 확인하기 위해 페이지를 새로고침한 뒤 다시 읽습니다.
+        client.reload().await?;
+        let saved = StudentInformation::with_parser(&ElementParser::new(client.body()))?;
+        let confirmed = changes
+            .iter()
+            .all(|(field, value)| saved_value(&saved, *field).map(str::trim) == Some(value));
+        Ok(ContactUpdateResult {
+            dry_run,
+            events,
+            messages,
+            confirmed,
+        })
+    }
+
+    /// 수정 버튼, 값 입력, 저장 버튼 이벤트를 전송하고 서버가 표시한 메시지를 반환합니다.
+    ///
+    /// 메시지 영역에 오류 메시지가 표시되면 [`ApplicationError::ContactUpdateError`]를 반환합니다.
+    async fn save(
+        client: &mut USaintClient,
+        changes: &[(ContactField, String)],
+        has_modify_button: bool,
+    ) -> Result<Vec<String>, RusaintError> {
+        if has_modify_button {
+            let event = ElementParser::new(client.body())
+                .read(ButtonPressEventCommand::new(Self::MODIFY_BUTTON))?;
+            client.process_event(false, event).await?;
+        }
+        for (field, value) in changes {
+            let event = {
+                let parser = ElementParser::new(client.body());
+                let input = parser.element_from_def(&Self::element(*field))?;
+                input.fire_event("Change".to_string(), change_parameters(input.id(), value))?
+            };
+            client.process_event(false, event).await?;
+        }
+        let event = ElementParser::new(client.body())
+            .read(ButtonPressEventCommand::new(Self::SAVE_BUTTON))?;
+        client.process_event(true, event).await?;
+
+        let (mut messages, error) = {
+            let parser = ElementParser::new(client.body());
+            let area = message_area(parser.document());
+            let error = area
+                .iter()
+                .find(|(_, is_error)| *is_error)
+                .map(|(message, _)| message.clone());
+            let mut messages = popup_messages(&parser);
+            messages.extend(area.into_iter().map(|(message, _)| message));
+            (messages, error)
+        };
+        close_popups(client).await?;
+        if let Some(error) = error {
+            return Err(ApplicationError::ContactUpdateError(error).into());
+        }
+        messages.dedup();
+        Ok(messages)
+    }
+}
+
+/// 입력 필드의 `Change` 이벤트 파라메터를 만듭니다.
+///
+/// wdpe에는 입력 필드의 값 변경 명령이 없어 직접 만들며, 값은 이벤트를 직렬화할 때 wdpe가 이스케이프하므로 그대로 전달합니다.
+fn change_parameters(element_id: &str, value: &str) -> HashMap<String, String> {
+    HashMap::from([
+        ("Id".to_string(), element_id.to_string()),
+        ("Value".to_string(), value.to_string()),
+    ])
+}
+
+/// 저장된 학생 정보에서 주어진 연락처 항목의 값을 가져옵니다.
+fn saved_value(information: &StudentInformation, field: ContactField) -> Option<&str> {
+    match field {
+        ContactField::Email => information.email(),
+        ContactField::TelNumber => information.tel_number(),
+        ContactField::MobileNumber => information.mobile_number(),
+        ContactField::PostCode => information.post_code(),
+        ContactField::Address => information.address().0,
+        ContactField::SpecificAddress => information.address().1,
+    }
+}
+
+/// 현재 열려있는 팝업 창의 텍스트를 가져옵니다.
+fn popup_messages(parser: &ElementParser) -> Vec<String> {
+    let selector = Selector::parse(r#"[ct="PW"] [ct="TV"]"#).unwrap();
+    parser
+        .document()
+        .select(&selector)
+        .filter_map(|elem| match ElementWrapper::from_ref(elem).ok()? {
+            ElementWrapper::TextView(text_view) => Some(text_view.text().trim().to_string()),
+            _ => None,
+        })
+        .filter(|text| !text.is_empty())
+        .collect()
+}
+
+/// WebDynpro 메시지 영역에 표시된 메시지와 각 메시지가 오류 메시지인지 여부를 가져옵니다.
+///
+/// 메시지 막대 또는 그 하위 엘리먼트의 클래스 이름에 "error"가 포함되어 있으면 오류 메시지로 봅니다.
+fn message_area(document: &Html) -> Vec<(String, bool)> {
+    let selector =
+        Selector::parse(r#"[ct="MB"], [class*="lsMessageBar"], [class*="urMsgBar"]"#).unwrap();
+    let mut messages: Vec<(String, bool)> = Vec::new();
+    for bar in document.select(&selector) {
+        let text = bar
+            .text()
+            .flat_map(str::split_whitespace)
+            .collect::<Vec<_>>()
+            .join(" ");
+        if text.is_empty() || messages.iter().any(|(message, _)| *message == text) {
+            continue;
+        }
+        let is_error = std::iter::once(bar)
+            .chain(bar.descendants().filter_map(ElementRef::wrap))
+            .any(|elem| {
+                elem.value()
+                    .classes()
+                    .any(|class| class.to_ascii_lowercase().contains("error"))
+            });
+        messages.push((text, is_error));
+    }
+    messages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_contact_update() {
+        let update = ContactUpdate {
+            email: Some(" student@soongsil.ac.kr ".to_string()),
+            mobile_number: Some("010-1234-5678".to_string()),
+            post_code: Some("06978".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(update.validate(), Ok(()));
+        assert_eq!(
+            update.changes()[0],
+            (ContactField::Email, "student@soongsil.ac.kr")
+        );
+        assert_eq!(
+            ContactUpdate::default().validate(),
+            Err(ContactValidationError::NoChanges)
+        );
+        let invalid = ContactUpdate {
+            mobile_number: Some("02-820-0114".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            invalid.validate(),
+            Err(ContactValidationError::InvalidPhoneNumber {
+                field: ContactField::MobileNumber,
+                ..
+            })
+        ));
+        let invalid = ContactUpdate {
+            email: Some("student@localhost".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            invalid.validate(),
+            Err(ContactValidationError::InvalidEmail(_))
+        ));
+        let update = ContactUpdate {
+            tel_number: Some("02-820-0114".to_string()),
+            post_code: Some("156-743".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(update.validate(), Ok(()));
+    }
+
+    #[test]
+    fn redacts_event_values() {
+        let event =
+            ContactUpdateEvent::change("MOB_NUMBER", ContactField::MobileNumber, "010-1234-5678");
+        assert_eq!(event.redacted().value(), Some("***-****-5678"));
+        assert_eq!(event.value(), Some("010-1234-5678"));
+        let event = ContactUpdateEvent::press("SAVE_BUTTON");
+        assert_eq!(event.redacted(), event);
+    }
+
+    #[test]
+    fn builds_change_parameters() {
+        let fields = [
+            (ContactField::Email, "SMTP_ADDR"),
+            (ContactField::TelNumber, "TEL_NUMBER"),
+            (ContactField::MobileNumber, "MOB_NUMBER"),
+            (ContactField::PostCode, "POST_CODE"),
+            (ContactField::Address, "CITY1"),
+            (ContactField::SpecificAddress, "STREET"),
+        ];
+        for (field, id) in fields {
+            let element_id = ContactUpdate::element(field).id().to_string();
+            assert_eq!(element_id, format!("ZCMW1001.ID_0001:VIW_DEFAULT.{id}"));
+            let parameters = change_parameters(&element_id, "값");
+            assert_eq!(parameters.len(), 2);
+            assert_eq!(parameters["Id"], element_id);
+            assert_eq!(parameters["Value"], "값");
+        }
+
+        let value = "상도로 369, 1층~2:3";
+        let event = unsafe {
+            InputField::fire_event_unchecked(
+                "Change".to_string(),
+                change_parameters("ZCMW1001.ID_0001:VIW_DEFAULT.STREET", value),
+                Default::default(),
+                HashMap::new(),
+            )
+        }
+        .to_string();
+        assert!(event.starts_with("InputField_Change~E002"));
+        assert!(event.contains(&format!("Value~E004{}", wdpe::event::escape_str(value))));
+        assert!(!event.contains(value));
+    }
+
+    #[test]
+    fn reads_message_area() {
+        let document = Html::parse_fragment(
+            r#"<div ct="MB" class="lsMessageBar lsMessageBar--error"><span class="lsMessageBar__text">이메일 형식이 올바르지 않습니다.</span></div>
+            <div ct="MB" class="lsMessageBar lsMessageBar--success"><span>저장되었습니다.</span></div>"#,
+        );
+        assert_eq!(
+            message_area(&document),
+            vec![
+                ("이메일 형식이 올바르지 않습니다.".to_string(), true),
+                ("저장되었습니다.".to_string(), false),
+            ]
+        );
+        assert!(message_area(&Html::parse_fragment("<div></div>")).is_empty());
+    }
+}
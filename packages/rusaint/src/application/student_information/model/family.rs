@@ -7,6 +7,7 @@ use serde::{
 
 use crate::application::utils::de_with::{deserialize_bool_string, deserialize_optional_string};
 use crate::application::utils::sap_table::try_table_into_with_scroll;
+use crate::redact::{Redact, mask_name, mask_phone};
 use crate::{
     application::student_information::StudentInformationApplication, client::USaintClient,
 };
@@ -158,6 +159,25 @@ impl StudentFamilyMember {
     }
 }
 
+impl Redact for StudentFamily {
+    fn redacted(&self) -> Self {
+        Self {
+            members: self.members.redacted(),
+        }
+    }
+}
+
+impl Redact for StudentFamilyMember {
+    fn redacted(&self) -> Self {
+        Self {
+            tel_number: self.tel_number.as_deref().map(mask_phone),
+            name: self.name.as_deref().map(mask_name),
+            mobile_number: self.mobile_number.as_deref().map(mask_phone),
+            ..self.clone()
+        }
+    }
+}
+
 impl<'a> FromSapTable<'a> for StudentFamilyMember {
     fn from_table(
         header: Option<&'a wdpe::element::complex::sap_table::SapTableHeader>,
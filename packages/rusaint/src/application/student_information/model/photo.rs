@@ -0,0 +1,130 @@
+use reqwest::header::CONTENT_TYPE;
+use serde::{Deserialize, Serialize};
+use wdpe::{
+    define_elements,
+    element::{Element, graphic::Image, parser::ElementParser},
+};
+
+use crate::{ApplicationError, RusaintError, client::USaintClient};
+
+/// 학생 사진
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct StudentPhoto {
+    /// 사진 바이너리
+    data: Vec<u8>,
+    /// 사진의 MIME 타입 (e.g. "image/jpeg")
+    mime_type: String,
+}
+
+impl<'a> StudentPhoto {
+    define_elements! {
+        // 사진
+        ST_IMAGE: Image<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.ST_IMAGE";
+    }
+
+    pub(crate) async fn with_client(client: &USaintClient) -> Result<StudentPhoto, RusaintError> {
+        let src = {
+            let parser = ElementParser::new(client.body());
+            let image = parser.element_from_def(&Self::ST_IMAGE)?;
+            image
+                .lsdata()
+                .src()
+                .filter(|src| !src.trim().is_empty())
+                .cloned()
+                .ok_or(ApplicationError::NoStudentPhoto)?
+        };
+        let url = client
+            .base_url()
+            .join(src.trim())
+            .map_err(|err| ApplicationError::PhotoFetchError(err.to_string()))?;
+        let response = client
+            .http_client()
+            .get(url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|err| ApplicationError::PhotoFetchError(err.to_string()))?;
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let data = response
+            .bytes()
+            .await
+            .map_err(|err| ApplicationError::PhotoFetchError(err.to_string()))?;
+        if data.is_empty() {
+            return Err(ApplicationError::NoStudentPhoto.into());
+        }
+        Ok(Self::new(data.to_vec(), content_type.as_deref()))
+    }
+
+    /// 응답의 `Content-Type`이 이미지 타입이 아니라면 사진 바이너리의 시그니처로 MIME 타입을 추측합니다.
+    pub(crate) fn new(data: Vec<u8>, content_type: Option<&str>) -> StudentPhoto {
+        let mime_type = content_type
+            .and_then(|content_type| content_type.split(';').next())
+            .map(str::trim)
+            .filter(|mime_type| mime_type.starts_with("image/"))
+            .map(str::to_string)
+            .unwrap_or_else(|| sniff_mime_type(&data).to_string());
+        Self { data, mime_type }
+    }
+
+    /// 사진 바이너리를 반환합니다.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// 사진의 MIME 타입을 반환합니다.
+    pub fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+
+    /// MIME 타입에 맞는 파일 확장자를 반환합니다.
+    pub fn extension(&self) -> &str {
+        match self.mime_type.as_str() {
+            "image/jpeg" | "image/pjpeg" => "jpg",
+            "image/png" => "png",
+            "image/gif" => "gif",
+            "image/bmp" => "bmp",
+            _ => "bin",
+        }
+    }
+
+    /// 사진 바이너리를 소유권과 함께 반환합니다.
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+fn sniff_mime_type(data: &[u8]) -> &'static str {
+    if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        "image/jpeg"
+    } else if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        "image/png"
+    } else if data.starts_with(b"GIF8") {
+        "image/gif"
+    } else if data.starts_with(b"BM") {
+        "image/bmp"
+    } else {
+        "application/octet-stream"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_mime_type() {
+        let jpeg = vec![0xFF, 0xD8, 0xFF, 0xE0, 0x00];
+        let photo = StudentPhoto::new(jpeg.clone(), Some("application/octet-stream"));
+        assert_eq!(photo.mime_type(), "image/jpeg");
+        assert_eq!(photo.extension(), "jpg");
+        let photo = StudentPhoto::new(jpeg, Some("image/png; charset=binary"));
+        assert_eq!(photo.mime_type(), "image/png");
+        let photo = StudentPhoto::new(b"unknown".to_vec(), None);
+        assert_eq!(photo.extension(), "bin");
+    }
+}
@@ -0,0 +1,239 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    StudentAcademicRecords, StudentBankAccount, StudentFamily, StudentGraduation,
+    StudentInformation, StudentQualification, StudentReligion, StudentResearchBankAccount,
+    StudentTransferRecords, StudentWorkInformation,
+};
+use crate::{
+    RusaintError, application::student_information::StudentInformationApplication, redact::Redact,
+};
+
+/// 학생 정보 프로필의 각 항목
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum StudentProfileSection {
+    /// 일반 학생 정보
+    General,
+    /// 졸업정보
+    Graduation,
+    /// 자격 관련 정보
+    Qualifications,
+    /// 직장정보
+    Work,
+    /// 가족정보
+    Family,
+    /// 종교정보
+    Religion,
+    /// 편입정보
+    Transfer,
+    /// 은행계좌 정보
+    BankAccount,
+    /// 학적상태 정보
+    AcademicRecord,
+    /// 연구비 입금 계좌
+    ResearchBankAccount,
+}
+
+impl Display for StudentProfileSection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Self::General => "일반",
+            Self::Graduation => "졸업",
+            Self::Qualifications => "자격",
+            Self::Work => "직장",
+            Self::Family => "가족",
+            Self::Religion => "종교",
+            Self::Transfer => "편입",
+            Self::BankAccount => "은행계좌",
+            Self::AcademicRecord => "학적상태",
+            Self::ResearchBankAccount => "연구비 계좌",
+        };
+        write!(f, "{str}")
+    }
+}
+
+/// 프로필의 한 항목을 가져오지 못한 이유
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct StudentProfileError {
+    /// 가져오지 못한 항목
+    section: StudentProfileSection,
+    /// 오류 메시지
+    message: String,
+}
+
+impl StudentProfileError {
+    /// 가져오지 못한 항목
+    pub fn section(&self) -> StudentProfileSection {
+        self.section
+    }
+
+    /// 오류 메시지
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// 학생 정보 애플리케이션의 모든 탭을 모은 프로필
+///
+/// 가져오지 못한 항목은 `None`이며, 그 이유는 [`StudentProfile::errors`]에 기록됩니다.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct StudentProfile {
+    general: Option<StudentInformation>,
+    graduation: Option<StudentGraduation>,
+    qualifications: Option<StudentQualification>,
+    work: Option<StudentWorkInformation>,
+    family: Option<StudentFamily>,
+    religion: Option<StudentReligion>,
+    transfer: Option<StudentTransferRecords>,
+    bank_account: Option<StudentBankAccount>,
+    academic_record: Option<StudentAcademicRecords>,
+    research_bank_account: Option<StudentResearchBankAccount>,
+    errors: Vec<StudentProfileError>,
+}
+
+impl StudentProfile {
+    pub(crate) async fn with_application(app: &mut StudentInformationApplication) -> Self {
+        let mut profile = Self::default();
+        profile.general = profile.collect(StudentProfileSection::General, app.general());
+        profile.graduation = profile.collect(StudentProfileSection::Graduation, app.graduation());
+        profile.qualifications =
+            profile.collect(StudentProfileSection::Qualifications, app.qualifications());
+        profile.work = profile.collect(StudentProfileSection::Work, app.work().await);
+        profile.family = profile.collect(StudentProfileSection::Family, app.family().await);
+        profile.religion = profile.collect(StudentProfileSection::Religion, app.religion().await);
+        profile.transfer = profile.collect(StudentProfileSection::Transfer, app.transfer().await);
+        profile.bank_account =
+            profile.collect(StudentProfileSection::BankAccount, app.bank_account().await);
+        profile.academic_record = profile.collect(
+            StudentProfileSection::AcademicRecord,
+            app.academic_record().await,
+        );
+        profile.research_bank_account = profile.collect(
+            StudentProfileSection::ResearchBankAccount,
+            app.research_bank_account().await,
+        );
+        profile
+    }
+
+    fn collect<T>(
+        &mut self,
+        section: StudentProfileSection,
+        result: Result<T, RusaintError>,
+    ) -> Option<T> {
+        result
+            .map_err(|err| {
+                self.errors.push(StudentProfileError {
+                    section,
+                    message: err.to_string(),
+                })
+            })
+            .ok()
+    }
+
+    /// 일반 학생 정보
+    pub fn general(&self) -> Option<&StudentInformation> {
+        self.general.as_ref()
+    }
+
+    /// 졸업정보
+    pub fn graduation(&self) -> Option<&StudentGraduation> {
+        self.graduation.as_ref()
+    }
+
+    /// 교직, 평생교육사, 7+1 프로그램 등 자격 관련 정보
+    pub fn qualifications(&self) -> Option<&StudentQualification> {
+        self.qualifications.as_ref()
+    }
+
+    /// 직장정보
+    pub fn work(&self) -> Option<&StudentWorkInformation> {
+        self.work.as_ref()
+    }
+
+    /// 가족정보
+    pub fn family(&self) -> Option<&StudentFamily> {
+        self.family.as_ref()
+    }
+
+    /// 종교정보
+    pub fn religion(&self) -> Option<&StudentReligion> {
+        self.religion.as_ref()
+    }
+
+    /// 편입정보
+    pub fn transfer(&self) -> Option<&StudentTransferRecords> {
+        self.transfer.as_ref()
+    }
+
+    /// 은행계좌 정보
+    pub fn bank_account(&self) -> Option<&StudentBankAccount> {
+        self.bank_account.as_ref()
+    }
+
+    /// 학적상태 정보
+    pub fn academic_record(&self) -> Option<&StudentAcademicRecords> {
+        self.academic_record.as_ref()
+    }
+
+    /// 연구비 입금 계좌
+    pub fn research_bank_account(&self) -> Option<&StudentResearchBankAccount> {
+        self.research_bank_account.as_ref()
+    }
+
+    /// 가져오지 못한 항목과 그 이유
+    pub fn errors(&self) -> &[StudentProfileError] {
+        &self.errors
+    }
+
+    /// 주어진 항목을 가져오지 못한 이유 (가져왔다면 `None`)
+    pub fn error(&self, section: StudentProfileSection) -> Option<&StudentProfileError> {
+        self.errors.iter().find(|error| error.section == section)
+    }
+
+    /// 모든 항목을 가져왔는지 여부
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl Redact for StudentProfile {
+    fn redacted(&self) -> Self {
+        Self {
+            general: self.general.redacted(),
+            work: self.work.redacted(),
+            family: self.family.redacted(),
+            bank_account: self.bank_account.redacted(),
+            research_bank_account: self.research_bank_account.redacted(),
+            ..self.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_section_errors() {
+        let mut profile = StudentProfile::default();
+        let religion: Option<StudentReligion> = profile.collect(
+            StudentProfileSection::Religion,
+            Err(RusaintError::InvalidClientError),
+        );
+        assert!(religion.is_none());
+        assert_eq!(
+            profile.collect(StudentProfileSection::Transfer, Ok(1)),
+            Some(1)
+        );
+        assert!(!profile.is_complete());
+        assert_eq!(profile.errors().len(), 1);
+        let error = profile.error(StudentProfileSection::Religion).unwrap();
+        assert_eq!(error.message(), "Invalid Client for target application");
+        assert!(profile.error(StudentProfileSection::Transfer).is_none());
+    }
+}
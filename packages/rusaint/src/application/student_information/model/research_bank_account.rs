@@ -1,5 +1,6 @@
 use crate::application::student_information::StudentInformationApplication;
 use crate::client::USaintClient;
+use crate::redact::{Redact, mask_digits, mask_name};
 use serde::{Deserialize, Serialize};
 use wdpe::command::WebDynproCommandExecutor;
 use wdpe::element::parser::ElementParser;
@@ -78,3 +79,16 @@ impl<'a> StudentResearchBankAccount {
         self.holder.as_deref()
     }
 }
+
+impl Redact for StudentResearchBankAccount {
+    fn redacted(&self) -> Self {
+        Self {
+            bank: self.bank.clone(),
+            account_number: self
+                .account_number
+                .as_deref()
+                .map(|account_number| mask_digits(account_number, 4)),
+            holder: self.holder.as_deref().map(mask_name),
+        }
+    }
+}
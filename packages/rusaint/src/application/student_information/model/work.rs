@@ -1,5 +1,6 @@
 use crate::application::student_information::StudentInformationApplication;
 use crate::client::USaintClient;
+use crate::redact::{Redact, mask_address, mask_all, mask_phone};
 use serde::{Deserialize, Serialize};
 use wdpe::command::WebDynproCommandExecutor;
 use wdpe::element::parser::ElementParser;
@@ -151,3 +152,16 @@ impl<'a> StudentWorkInformation {
         self.fax_number.as_deref()
     }
 }
+
+impl Redact for StudentWorkInformation {
+    fn redacted(&self) -> Self {
+        Self {
+            zip_code: self.zip_code.as_deref().map(mask_all),
+            address: self.address.as_deref().map(mask_address),
+            specific_address: self.specific_address.as_deref().map(mask_all),
+            tel_number: self.tel_number.as_deref().map(mask_phone),
+            fax_number: self.fax_number.as_deref().map(mask_phone),
+            ..self.clone()
+        }
+    }
+}
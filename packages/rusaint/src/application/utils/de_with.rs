@@ -1,3 +1,4 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use serde::{Deserialize, Deserializer};
 
 use crate::model::SemesterType;
@@ -72,3 +73,69 @@ pub(crate) fn deserialize_comma_u64_string<'de, D: Deserializer<'de>>(
         .parse()
         .map_err(serde::de::Error::custom)
 }
+
+/// u-saint에서 사용하는 날짜 표기(e.g. "2024.03.05", "2024-03-05", "20240305")를 해석합니다.
+pub(crate) fn parse_date(value: &str) -> Option<NaiveDate> {
+    let value = value.trim();
+    ["%Y.%m.%d", "%Y-%m-%d", "%Y%m%d", "%Y/%m/%d"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+}
+
+/// u-saint에서 사용하는 시각 표기(e.g. "10:30:15", "103015", "10:30")를 해석합니다.
+pub(crate) fn parse_time(value: &str) -> Option<NaiveTime> {
+    let value = value.trim();
+    ["%H:%M:%S", "%H%M%S", "%H:%M", "%H%M"]
+        .iter()
+        .find_map(|format| NaiveTime::parse_from_str(value, format).ok())
+}
+
+/// 날짜와 시각을 함께 해석합니다. 시각이 비어 있다면 자정으로 간주합니다.
+pub(crate) fn parse_date_time(date: &str, time: &str) -> Option<NaiveDateTime> {
+    let date = parse_date(date)?;
+    let time = if time.trim().is_empty() {
+        NaiveTime::MIN
+    } else {
+        parse_time(time)?
+    };
+    Some(date.and_time(time))
+}
+
+pub(crate) fn deserialize_optional_date<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<NaiveDate>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    // 빈 값이나 "0000.00.00"은 날짜가 없는 것으로 취급합니다.
+    if value
+        .trim()
+        .chars()
+        .all(|c| c == '0' || c == '.' || c == '-')
+    {
+        return Ok(None);
+    }
+    parse_date(&value)
+        .map(Some)
+        .ok_or_else(|| serde::de::Error::custom(format!("Invalid date format: {value}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_dates_and_times() {
+        let date = NaiveDate::from_ymd_opt(2025, 2, 10).unwrap();
+        assert_eq!(parse_date("2025.02.10"), Some(date));
+        assert_eq!(parse_date("20250210"), Some(date));
+        assert_eq!(parse_date(""), None);
+        let time = NaiveTime::from_hms_opt(10, 30, 15).unwrap();
+        assert_eq!(
+            parse_date_time("20250210", "103015"),
+            Some(date.and_time(time))
+        );
+        assert_eq!(
+            parse_date_time("2025-02-10", ""),
+            Some(date.and_time(NaiveTime::MIN))
+        );
+    }
+}
@@ -6,6 +6,7 @@
 
 use crate::ApplicationError;
 use crate::RusaintError;
+use crate::redact::mask_all;
 
 /// OZ Report Server의 공개 게스트 크리덴셜.
 /// OZ viewer는 인증 없이 데이터를 조회할 수 있는 게스트 계정을 제공하며,
@@ -74,6 +75,26 @@ fn convert_js_hex_escapes(input: &str) -> String {
     result
 }
 
+/// 로그와 오류 메시지에 학번 등의 개인정보가 남지 않도록 OZ URL의 `pValue` 값을 가립니다.
+fn redact_oz_url(oz_url: &str) -> String {
+    let Some((base, query)) = oz_url.split_once('?') else {
+        return oz_url.to_string();
+    };
+    let query = url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(
+            url::form_urlencoded::parse(query.as_bytes()).map(|(key, value)| {
+                let value = if key == "pValue" {
+                    mask_all(&value)
+                } else {
+                    value.into_owned()
+                };
+                (key, value)
+            }),
+        )
+        .finish();
+    format!("{base}?{query}")
+}
+
 /// script_calls에서 openExternalWindow URL을 추출합니다.
 pub(crate) fn extract_oz_url_from_script_calls(
     script_calls: &[String],
@@ -82,7 +103,7 @@ pub(crate) fn extract_oz_url_from_script_calls(
         .iter()
         .find(|call| call.contains("openExternalWindow"))
         .and_then(|call| {
-            tracing::debug!("Found openExternalWindow script_call: {}", call);
+            tracing::debug!("Found openExternalWindow script_call");
             // application.exec("openExternalWindow",{...,"url":"THE_URL",...});
             // JSON 부분만 추출하여 serde_json으로 파싱
             let json_start = call.find('{')?;
@@ -95,12 +116,12 @@ pub(crate) fn extract_oz_url_from_script_calls(
         })
         .ok_or_else(|| {
             ApplicationError::OzDataFetchError(format!(
-                "No openExternalWindow URL found in script_calls: {:?}",
-                script_calls
+                "No openExternalWindow URL found in {} script_calls",
+                script_calls.len()
             ))
         })?;
 
-    tracing::debug!("Parsed OZ URL: {}", oz_url_raw);
+    tracing::debug!("Parsed OZ URL: {}", redact_oz_url(&oz_url_raw));
 
     if oz_url_raw.starts_with("http://") || oz_url_raw.starts_with("https://") {
         Ok(oz_url_raw)
@@ -116,8 +137,13 @@ pub(crate) fn extract_oz_url_from_script_calls(
 /// OZ URL 문자열을 파싱하여 [`OzUrlParams`]를 반환합니다.
 pub(crate) fn parse_oz_url_params(oz_url: &str) -> Result<OzUrlParams, RusaintError> {
     let parsed_url = url::Url::parse(oz_url).map_err(|e| {
-        ApplicationError::OzDataFetchError(format!("Failed to parse OZ URL '{}': {}", oz_url, e))
+        ApplicationError::OzDataFetchError(format!(
+            "Failed to parse OZ URL '{}': {}",
+            redact_oz_url(oz_url),
+            e
+        ))
     })?;
+    let redacted_url = redact_oz_url(oz_url);
 
     let base_url = format!(
         "{}://{}/oz70",
@@ -132,22 +158,23 @@ pub(crate) fn parse_oz_url_params(oz_url: &str) -> Result<OzUrlParams, RusaintEr
         .map(|(k, v)| (k.to_string(), v.to_string()))
         .collect();
 
-    tracing::debug!("Query pairs: {:?}", query_pairs);
+    tracing::debug!("Query keys: {:?}", query_pairs.keys());
 
     let ozrname = query_pairs.get("ozrname").cloned().ok_or_else(|| {
         ApplicationError::OzDataFetchError(format!(
-            "Missing ozrname in URL: {} (query_pairs: {:?})",
-            oz_url, query_pairs
+            "Missing ozrname in URL: {} (query keys: {:?})",
+            redacted_url,
+            query_pairs.keys()
         ))
     })?;
     let category = query_pairs.get("category").cloned().ok_or_else(|| {
-        ApplicationError::OzDataFetchError(format!("Missing category in URL: {}", oz_url))
+        ApplicationError::OzDataFetchError(format!("Missing category in URL: {}", redacted_url))
     })?;
     let p_names_str = query_pairs.get("pName").cloned().ok_or_else(|| {
-        ApplicationError::OzDataFetchError(format!("Missing pName in URL: {}", oz_url))
+        ApplicationError::OzDataFetchError(format!("Missing pName in URL: {}", redacted_url))
     })?;
     let p_values_str = query_pairs.get("pValue").cloned().ok_or_else(|| {
-        ApplicationError::OzDataFetchError(format!("Missing pValue in URL: {}", oz_url))
+        ApplicationError::OzDataFetchError(format!("Missing pValue in URL: {}", redacted_url))
     })?;
 
     let p_names: Vec<&str> = p_names_str.split(',').collect();
@@ -158,7 +185,7 @@ pub(crate) fn parse_oz_url_params(oz_url: &str) -> Result<OzUrlParams, RusaintEr
             "pName count ({}) does not match pValue count ({}) in URL: {}",
             p_names.len(),
             p_values.len(),
-            oz_url
+            redacted_url
         ))
         .into());
     }
@@ -187,12 +214,16 @@ pub(crate) async fn fetch_data_module(
     http_client: Option<&reqwest::Client>,
 ) -> Result<ozra::types::DataModuleResponse, RusaintError> {
     tracing::debug!(
-        "OZ params: base_url={}, ozrname={}, category={}, odi={}, params={:?}",
+        "OZ params: base_url={}, ozrname={}, category={}, odi={}, param names={:?}",
         oz_params.base_url,
         oz_params.ozrname,
         oz_params.category,
         oz_params.odi_name,
-        oz_params.params
+        oz_params
+            .params
+            .iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>()
     );
 
     let mut oz_builder = ozra::client::OzClientBuilder::new(
@@ -421,6 +452,25 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn redacts_p_value_in_url() {
+        let url =
+            "https://office.ssu.ac.kr/oz70/viewer?ozrname=r&category=c&pName=UNAME&pValue=20231234";
+        assert_eq!(
+            redact_oz_url(url),
+            "https://office.ssu.ac.kr/oz70/viewer?ozrname=r&category=c&pName=UNAME&pValue=***"
+        );
+        let result = parse_oz_url_params(
+            "https://office.ssu.ac.kr/oz70/viewer?category=c&pName=UNAME&pValue=20231234",
+        );
+        let Err(RusaintError::ApplicationError(ApplicationError::OzDataFetchError(message))) =
+            result
+        else {
+            panic!("expected OzDataFetchError");
+        };
+        assert!(!message.contains("20231234"));
+    }
+
     #[test]
     fn parse_params_invalid_url() {
         let result = parse_oz_url_params("not a valid url");
@@ -1,3 +1,5 @@
+use chrono::{Datelike, FixedOffset, NaiveDate, Utc};
+
 use crate::client::USaintClient;
 use crate::model::SemesterType;
 use wdpe::{
@@ -37,7 +39,130 @@ pub(crate) fn get_selected_semester(
         "091" => SemesterType::Summer,
         "092" => SemesterType::Two,
         "093" => SemesterType::Winter,
-        _ => unreachable!("Invalid semester key"),
+        key => {
+            return Err(ElementError::InvalidContent {
+                element: semester_def.id().to_string(),
+                content: format!("Unknown semester key: {key}"),
+            }
+            .into());
+        }
     };
     Ok((year, semester))
 }
+
+/// 한국 표준시(UTC+9) 기준 오늘 날짜를 반환합니다.
+pub(crate) fn today_kst() -> NaiveDate {
+    let kst = FixedOffset::east_opt(9 * 3600).expect("UTC+9 is a valid offset");
+    Utc::now().with_timezone(&kst).date_naive()
+}
+
+/// 주어진 날짜를 기준으로 가장 최근에 시작한 정규학기(1학기, 2학기)를 반환합니다.
+/// 1학기는 3월, 2학기는 9월에 시작하는 것으로 간주합니다.
+pub(crate) fn latest_regular_semester(date: NaiveDate) -> (u32, SemesterType) {
+    let year = date.year() as u32;
+    match date.month() {
+        9.. => (year, SemesterType::Two),
+        3.. => (year, SemesterType::One),
+        _ => (year - 1, SemesterType::Two),
+    }
+}
+
+/// 주어진 날짜가 속한 학기를 날짜 범위로 추정합니다. 방학은 그 기간의 계절학기로 간주합니다.
+///
+/// - 3월 1일 ~ 6월 20일: 1학기
+/// - 6월 21일 ~ 8월 31일: 여름학기
+/// - 9월 1일 ~ 12월 20일: 2학기
+/// - 12월 21일 ~ 다음 해 2월 말일: 겨울학기 (12월이 속한 학년도)
+pub(crate) fn semester_on(date: NaiveDate) -> (u32, SemesterType) {
+    let year = date.year() as u32;
+    match (date.month(), date.day()) {
+        (1 | 2, _) => (year - 1, SemesterType::Winter),
+        (3..=5, _) | (6, ..=20) => (year, SemesterType::One),
+        (6..=8, _) => (year, SemesterType::Summer),
+        (9..=11, _) | (12, ..=20) => (year, SemesterType::Two),
+        _ => (year, SemesterType::Winter),
+    }
+}
+
+/// `from_year` 1학기부터 `until` 학기까지의 모든 학기(계절학기 포함)를 순서대로 반환합니다.
+pub(crate) fn semesters_between(
+    from_year: u32,
+    until: (u32, SemesterType),
+) -> Vec<(u32, SemesterType)> {
+    const ORDER: [SemesterType; 4] = [
+        SemesterType::One,
+        SemesterType::Summer,
+        SemesterType::Two,
+        SemesterType::Winter,
+    ];
+    let (until_year, until_semester) = until;
+    let until_index = ORDER.iter().position(|s| *s == until_semester).unwrap_or(3);
+    (from_year..=until_year)
+        .flat_map(|year| ORDER.iter().enumerate().map(move |(i, s)| (year, i, *s)))
+        .filter(|(year, i, _)| *year < until_year || *i <= until_index)
+        .map(|(year, _, semester)| (year, semester))
+        .collect()
+}
+
+/// `from_year` 1학기부터 `until` 학기까지의 정규학기(1학기, 2학기)를 순서대로 반환합니다.
+pub(crate) fn regular_semesters_between(
+    from_year: u32,
+    until: (u32, SemesterType),
+) -> Vec<(u32, SemesterType)> {
+    semesters_between(from_year, until)
+        .into_iter()
+        .filter(|(_, semester)| matches!(semester, SemesterType::One | SemesterType::Two))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_latest_regular_semester() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(
+            latest_regular_semester(date(2025, 2, 28)),
+            (2024, SemesterType::Two)
+        );
+        assert_eq!(
+            latest_regular_semester(date(2025, 3, 2)),
+            (2025, SemesterType::One)
+        );
+        assert_eq!(
+            latest_regular_semester(date(2025, 9, 1)),
+            (2025, SemesterType::Two)
+        );
+    }
+
+    #[test]
+    fn estimates_semester_by_date() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(semester_on(date(2025, 2, 28)), (2024, SemesterType::Winter));
+        assert_eq!(semester_on(date(2025, 6, 20)), (2025, SemesterType::One));
+        assert_eq!(semester_on(date(2025, 6, 21)), (2025, SemesterType::Summer));
+        assert_eq!(semester_on(date(2025, 12, 20)), (2025, SemesterType::Two));
+        assert_eq!(
+            semester_on(date(2025, 12, 21)),
+            (2025, SemesterType::Winter)
+        );
+    }
+
+    #[test]
+    fn lists_regular_semesters() {
+        assert_eq!(
+            regular_semesters_between(2024, (2025, SemesterType::One)),
+            vec![
+                (2024, SemesterType::One),
+                (2024, SemesterType::Two),
+                (2025, SemesterType::One)
+            ]
+        );
+        assert!(regular_semesters_between(2026, (2025, SemesterType::Two)).is_empty());
+        assert_eq!(
+            semesters_between(2025, (2025, SemesterType::Summer)),
+            vec![(2025, SemesterType::One), (2025, SemesterType::Summer)]
+        );
+    }
+}
@@ -2,6 +2,9 @@ use thiserror::Error;
 
 use wdpe::error::WebDynproError;
 
+use crate::application::personal_course_schedule::model::ScheduleParseError;
+use crate::application::student_information::model::ContactValidationError;
+
 /// Rusaint 애플리케이션에서 반환하는 오류
 #[derive(Error, Debug)]
 pub enum RusaintError {
@@ -45,10 +48,31 @@ pub enum ApplicationError {
     /// 찾고자 하는 강의의 강의평가 정보가 없음
     #[error("No Lecture assessment found")]
     NoLectureAssessments,
+    /// 강의평가 상세 결과에서 문항별 결과를 찾을 수 없음
+    #[error("No question results found in lecture assessment detail")]
+    NoAssessmentDetail,
     /// 조건에 맞는 강의를 찾을 수 없음
     #[error("No lecture found")]
     NoLectureResult,
     /// OZ 서버 데이터 가져오기 실패
     #[error("Failed to fetch OZ data: {0}")]
     OzDataFetchError(String),
+    /// 저장된 스냅샷을 읽거나 쓸 수 없음
+    #[error("Failed to read or write snapshot: {0}")]
+    SnapshotError(String),
+    /// 학생 사진이 등록되어 있지 않음
+    #[error("No student photo provided")]
+    NoStudentPhoto,
+    /// 학생 사진 가져오기 실패
+    #[error("Failed to fetch student photo: {0}")]
+    PhotoFetchError(String),
+    /// 학생 연락처 수정값이 올바르지 않음
+    #[error("Invalid contact update: {0}")]
+    ContactValidationError(#[from] ContactValidationError),
+    /// 학생 연락처 저장 시 서버가 오류를 표시함
+    #[error("Failed to save contact: {0}")]
+    ContactUpdateError(String),
+    /// 개인수업시간표의 셀을 해석할 수 없음
+    #[error("Failed to parse personal course schedule: {0}")]
+    ScheduleParseError(#[from] ScheduleParseError),
 }
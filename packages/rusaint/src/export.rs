@@ -0,0 +1,394 @@
+use std::fmt::Write;
+
+use chrono::{NaiveDate, NaiveDateTime, TimeDelta, Utc};
+
+/// 표 형식으로 내보낼 수 있는 행
+pub trait TableRow {
+    /// 표의 열 이름
+    fn headers() -> Vec<&'static str>;
+
+    /// 행의 각 열 값
+    fn row(&self) -> Vec<String>;
+}
+
+/// 주어진 행들을 CSV 형식으로 변환합니다.
+pub fn to_csv<'a, T: TableRow + 'a>(rows: impl IntoIterator<Item = &'a T>) -> String {
+    let mut buf = String::new();
+    write_csv_line(&mut buf, T::headers().into_iter());
+    for row in rows {
+        write_csv_line(&mut buf, row.row().iter().map(String::as_str));
+    }
+    buf
+}
+
+fn write_csv_line<'a>(buf: &mut String, fields: impl Iterator<Item = &'a str>) {
+    let line = fields.map(escape_csv).collect::<Vec<_>>().join(",");
+    buf.push_str(&line);
+    buf.push_str("\r\n");
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// 주어진 행들을 Markdown 표 형식으로 변환합니다.
+pub fn to_markdown<'a, T: TableRow + 'a>(rows: impl IntoIterator<Item = &'a T>) -> String {
+    let headers = T::headers();
+    let mut buf = String::new();
+    write_markdown_line(&mut buf, headers.iter().copied());
+    write_markdown_line(&mut buf, headers.iter().map(|_| "---"));
+    for row in rows {
+        write_markdown_line(&mut buf, row.row().iter().map(String::as_str));
+    }
+    buf
+}
+
+fn write_markdown_line<'a>(buf: &mut String, fields: impl Iterator<Item = &'a str>) {
+    buf.push('|');
+    for field in fields {
+        let field = field.replace('|', "\\|").replace('\n', "<br>");
+        let _ = write!(buf, " {field} |");
+    }
+    buf.push('\n');
+}
+
+/// 주어진 행들을 HTML `<table>` 요소로 변환합니다.
+pub fn to_html_table<'a, T: TableRow + 'a>(rows: impl IntoIterator<Item = &'a T>) -> String {
+    let mut buf = String::from("<table>\n<thead>\n<tr>");
+    for header in T::headers() {
+        let _ = write!(buf, "<th>{}</th>", escape_html(header));
+    }
+    buf.push_str("</tr>\n</thead>\n<tbody>\n");
+    for row in rows {
+        buf.push_str("<tr>");
+        for field in row.row() {
+            let _ = write!(buf, "<td>{}</td>", escape_html(&field));
+        }
+        buf.push_str("</tr>\n");
+    }
+    buf.push_str("</tbody>\n</table>\n");
+    buf
+}
+
+/// 주어진 제목과 본문으로 인쇄 가능한 단독 HTML 문서를 만듭니다. 본문은 이스케이프되지 않습니다.
+pub fn html_document(title: &str, body: &str) -> String {
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="ko">
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body {{ font-family: sans-serif; margin: 2em; }}
+table {{ border-collapse: collapse; width: 100%; margin-bottom: 1.5em; }}
+th, td {{ border: 1px solid #999; padding: 4px 8px; text-align: left; }}
+th {{ background: #eee; }}
+@media print {{ body {{ margin: 0; }} section {{ page-break-inside: avoid; }} }}
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+{body}</body>
+</html>
+"#,
+        title = escape_html(title)
+    )
+}
+
+/// HTML 특수 문자를 이스케이프합니다.
+pub fn escape_html(value: &str) -> String {
+    let mut buf = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => buf.push_str("&amp;"),
+            '<' => buf.push_str("&lt;"),
+            '>' => buf.push_str("&gt;"),
+            '"' => buf.push_str("&quot;"),
+            '\'' => buf.push_str("&#39;"),
+            c => buf.push(c),
+        }
+    }
+    buf
+}
+
+/// iCalendar로 내보낼 일정
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CalendarEvent {
+    summary: String,
+    location: String,
+    description: String,
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    all_day: bool,
+    weekly_until: Option<NaiveDate>,
+}
+
+impl CalendarEvent {
+    /// 한국 표준시 기준의 시작, 종료 일시로 일정을 만듭니다.
+    pub fn new(summary: impl Into<String>, start: NaiveDateTime, end: NaiveDateTime) -> Self {
+        Self {
+            summary: summary.into(),
+            location: String::new(),
+            description: String::new(),
+            start,
+            end,
+            all_day: false,
+            weekly_until: None,
+        }
+    }
+
+    /// 하루 종일 진행되는 일정을 만듭니다.
+    pub fn all_day(summary: impl Into<String>, date: NaiveDate) -> Self {
+        let start = date.and_time(Default::default());
+        Self {
+            all_day: true,
+            ..Self::new(summary, start, start + TimeDelta::days(1))
+        }
+    }
+
+    /// 장소를 지정합니다.
+    pub fn with_location(mut self, location: impl Into<String>) -> Self {
+        self.location = location.into();
+        self
+    }
+
+    /// 설명을 지정합니다.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// 주어진 날짜까지 매주 반복되도록 지정합니다.
+    pub fn weekly_until(mut self, until: NaiveDate) -> Self {
+        self.weekly_until = Some(until);
+        self
+    }
+
+    /// 일정 제목
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    /// 장소
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    /// 설명
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// 시작 일시
+    pub fn start(&self) -> NaiveDateTime {
+        self.start
+    }
+
+    /// 종료 일시
+    pub fn end(&self) -> NaiveDateTime {
+        self.end
+    }
+
+    /// 하루 종일 진행되는 일정인지 여부
+    pub fn is_all_day(&self) -> bool {
+        self.all_day
+    }
+
+    /// 매주 반복되는 일정의 마지막 날짜
+    pub fn repeat_until(&self) -> Option<NaiveDate> {
+        self.weekly_until
+    }
+}
+
+const ICS_TIMEZONE: &str = "Asia/Seoul";
+
+/// 주어진 일정들을 iCalendar(RFC 5545) 형식으로 변환합니다. 모든 일시는 한국 표준시로 간주합니다.
+pub fn to_ics<'a>(name: &str, events: impl IntoIterator<Item = &'a CalendarEvent>) -> String {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    let mut buf = String::new();
+    for line in [
+        "BEGIN:VCALENDAR",
+        "VERSION:2.0",
+        "PRODID:-//EATSTEAK//rusaint//KO",
+        "CALSCALE:GREGORIAN",
+        "METHOD:PUBLISH",
+    ] {
+        write_ics_line(&mut buf, line);
+    }
+    write_ics_line(&mut buf, &format!("X-WR-CALNAME:{}", escape_ics(name)));
+    write_ics_line(&mut buf, &format!("X-WR-TIMEZONE:{ICS_TIMEZONE}"));
+    for line in [
+        "BEGIN:VTIMEZONE",
+        &format!("TZID:{ICS_TIMEZONE}"),
+        "BEGIN:STANDARD",
+        "DTSTART:19700101T000000",
+        "TZOFFSETFROM:+0900",
+        "TZOFFSETTO:+0900",
+        "TZNAME:KST",
+        "END:STANDARD",
+        "END:VTIMEZONE",
+    ] {
+        write_ics_line(&mut buf, line);
+    }
+    for event in events {
+        write_ics_event(&mut buf, event, &stamp);
+    }
+    write_ics_line(&mut buf, "END:VCALENDAR");
+    buf
+}
+
+/// 일정의 내용으로 UID를 만듭니다. 같은 일정은 Rust 버전이나 실행 환경과 관계없이 항상 같은 UID를 가집니다.
+fn ics_uid(event: &CalendarEvent) -> String {
+    let format = "%Y%m%dT%H%M%S";
+    let key = [
+        event.summary.clone(),
+        event.location.clone(),
+        event.start.format(format).to_string(),
+        event.end.format(format).to_string(),
+        event.all_day.to_string(),
+        event
+            .weekly_until
+            .map(|until| until.format("%Y%m%d").to_string())
+            .unwrap_or_default(),
+    ]
+    .join("\u{1f}");
+    // FNV-1a (64비트)
+    let hash = key.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    });
+    format!("{}-{hash:016x}@rusaint", event.start.format(format))
+}
+
+fn write_ics_event(buf: &mut String, event: &CalendarEvent, stamp: &str) {
+    write_ics_line(buf, "BEGIN:VEVENT");
+    write_ics_line(buf, &format!("UID:{}", ics_uid(event)));
+    write_ics_line(buf, &format!("DTSTAMP:{stamp}"));
+    if event.all_day {
+        write_ics_line(
+            buf,
+            &format!("DTSTART;VALUE=DATE:{}", event.start.format("%Y%m%d")),
+        );
+        write_ics_line(
+            buf,
+            &format!("DTEND;VALUE=DATE:{}", event.end.format("%Y%m%d")),
+        );
+    } else {
+        let format = "%Y%m%dT%H%M%S";
+        write_ics_line(
+            buf,
+            &format!("DTSTART;TZID={ICS_TIMEZONE}:{}", event.start.format(format)),
+        );
+        write_ics_line(
+            buf,
+            &format!("DTEND;TZID={ICS_TIMEZONE}:{}", event.end.format(format)),
+        );
+    }
+    if let Some(until) = event.weekly_until {
+        // DTSTART에 시간대가 지정된 경우 UNTIL은 UTC로 표기해야 합니다.
+        let until = until.and_hms_opt(23, 59, 59).unwrap_or_default() - TimeDelta::hours(9);
+        write_ics_line(
+            buf,
+            &format!("RRULE:FREQ=WEEKLY;UNTIL={}", until.format("%Y%m%dT%H%M%SZ")),
+        );
+    }
+    write_ics_line(buf, &format!("SUMMARY:{}", escape_ics(&event.summary)));
+    if !event.location.is_empty() {
+        write_ics_line(buf, &format!("LOCATION:{}", escape_ics(&event.location)));
+    }
+    if !event.description.is_empty() {
+        write_ics_line(
+            buf,
+            &format!("DESCRIPTION:{}", escape_ics(&event.description)),
+        );
+    }
+    write_ics_line(buf, "END:VEVENT");
+}
+
+/// 한 줄을 75 옥텟 단위로 접어서 CRLF와 함께 추가합니다.
+fn write_ics_line(buf: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            buf.push_str("\r\n ");
+            width = 1;
+        }
+        buf.push(c);
+        width += c.len_utf8();
+    }
+    buf.push_str("\r\n");
+}
+
+/// iCalendar TEXT 값의 특수 문자를 이스케이프합니다.
+fn escape_ics(value: &str) -> String {
+    let mut buf = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => buf.push_str("\\\\"),
+            ';' => buf.push_str("\\;"),
+            ',' => buf.push_str("\\,"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => {}
+            c => buf.push(c),
+        }
+    }
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Row(&'static str, &'static str);
+
+    impl TableRow for Row {
+        fn headers() -> Vec<&'static str> {
+            vec!["이름", "값"]
+        }
+
+        fn row(&self) -> Vec<String> {
+            vec![self.0.to_string(), self.1.to_string()]
+        }
+    }
+
+    #[test]
+    fn escapes_special_characters() {
+        let rows = [Row("a,b", "say \"hi\""), Row("x|y", "<b>")];
+        assert_eq!(
+            to_csv(&rows),
+            "이름,값\r\n\"a,b\",\"say \"\"hi\"\"\"\r\nx|y,<b>\r\n"
+        );
+        assert_eq!(
+            to_markdown(&rows),
+            "| 이름 | 값 |\n| --- | --- |\n| a,b | say \"hi\" |\n| x\\|y | <b> |\n"
+        );
+        assert!(to_html_table(&rows).contains("<td>&lt;b&gt;</td>"));
+    }
+
+    #[test]
+    fn writes_ics() {
+        let date = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+        let event = CalendarEvent::new(
+            "데이터베이스; 01분반",
+            date.and_hms_opt(9, 0, 0).unwrap(),
+            date.and_hms_opt(10, 15, 0).unwrap(),
+        )
+        .with_location("정보과학관 21203")
+        .weekly_until(NaiveDate::from_ymd_opt(2025, 6, 20).unwrap());
+        let ics = to_ics("시간표", [&event]);
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains("DTSTART;TZID=Asia/Seoul:20250303T090000\r\n"));
+        assert!(ics.contains("RRULE:FREQ=WEEKLY;UNTIL=20250620T145959Z\r\n"));
+        assert!(ics.contains("SUMMARY:데이터베이스\\; 01분반\r\n"));
+        assert_eq!(ics_uid(&event), "20250303T090000-0c77eab5cd46553d@rusaint");
+        let long = "가".repeat(40);
+        let mut buf = String::new();
+        write_ics_line(&mut buf, &long);
+        assert!(buf.lines().all(|line| line.len() <= 75));
+        assert_eq!(buf.replace("\r\n ", ""), format!("{long}\r\n"));
+    }
+}
@@ -75,6 +75,14 @@ pub use session::USaintSession;
 /// u-saint 애플리케이션에서 공통으로 사용하는 데이터
 pub mod model;
 
+#[cfg(feature = "application")]
+/// 조회한 데이터를 CSV, Markdown, HTML 표와 iCalendar로 내보내기 위한 도구
+pub mod export;
+
+#[cfg(feature = "application")]
+/// 출력하거나 로그로 남기는 데이터에서 민감한 개인정보를 가리기 위한 도구
+pub mod redact;
+
 pub(crate) mod utils;
 
 #[cfg(feature = "uniffi")]
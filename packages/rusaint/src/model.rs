@@ -1,10 +1,11 @@
 use serde::{Deserialize, Serialize};
-use std::fmt::Display;
+use std::{convert::Infallible, fmt::Display, str::FromStr};
 
 /// 학기 종류
 ///
 /// 각 애플리케이션에서의 변환은 애플리케이션 내에서 직접 처리하여야 합니다.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+/// 같은 학년도 안에서 학기가 진행되는 순서대로 비교됩니다.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum SemesterType {
     /// 1학기
@@ -28,3 +29,168 @@ impl Display for SemesterType {
         write!(f, "{str}")
     }
 }
+
+/// 과목번호
+///
+/// u-saint의 각 애플리케이션에서 문자열 또는 숫자로 표현되는 과목번호를 공통으로 표현합니다.
+/// 앞뒤 공백을 제외한 원본 문자열을 그대로 보존하며, 문자열로 직렬화됩니다. 역직렬화 시에는 숫자도 허용합니다.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(transparent)]
+pub struct LectureCode(String);
+
+impl LectureCode {
+    /// 주어진 문자열로 과목번호를 만듭니다. 앞뒤 공백은 제거됩니다.
+    pub fn new(code: impl AsRef<str>) -> Self {
+        Self(code.as_ref().trim().to_string())
+    }
+
+    /// 과목번호 문자열을 반환합니다.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// 과목번호가 비어 있는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Display for LectureCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for LectureCode {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl AsRef<str> for LectureCode {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for LectureCode {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for LectureCode {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<u32> for LectureCode {
+    fn from(value: u32) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<LectureCode> for String {
+    fn from(value: LectureCode) -> Self {
+        value.0
+    }
+}
+
+impl PartialEq<str> for LectureCode {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for LectureCode {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<'de> Deserialize<'de> for LectureCode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct LectureCodeVisitor;
+
+        impl serde::de::Visitor<'_> for LectureCodeVisitor {
+            type Value = LectureCode;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a lecture code string or number")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(LectureCode::new(v))
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(LectureCode(v.to_string()))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(LectureCode(v.to_string()))
+            }
+        }
+
+        deserializer.deserialize_any(LectureCodeVisitor)
+    }
+}
+
+/// 과목번호와 분반으로 구분되는 강의
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct LectureSection {
+    /// 과목번호
+    code: LectureCode,
+    /// 분반 (분반이 없는 강의라면 `None`)
+    division: Option<String>,
+}
+
+impl LectureSection {
+    /// 과목번호와 분반으로 강의를 만듭니다. 빈 분반은 `None`으로 간주합니다.
+    pub fn new(code: LectureCode, division: Option<String>) -> Self {
+        let division = division
+            .map(|division| division.trim().to_string())
+            .filter(|division| !division.is_empty());
+        Self { code, division }
+    }
+
+    /// 과목번호
+    pub fn code(&self) -> &LectureCode {
+        &self.code
+    }
+
+    /// 분반
+    pub fn division(&self) -> Option<&str> {
+        self.division.as_deref()
+    }
+}
+
+impl Display for LectureSection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.division {
+            Some(division) => write!(f, "{}-{division}", self.code),
+            None => write!(f, "{}", self.code),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lecture_code_serde_is_lossless() {
+        let code: LectureCode = serde_json::from_str("\" 0210001 \"").unwrap();
+        assert_eq!(code, "0210001");
+        assert_eq!(serde_json::to_string(&code).unwrap(), "\"0210001\"");
+        let code: LectureCode = serde_json::from_str("2150001").unwrap();
+        assert_eq!(code, LectureCode::from(2150001));
+        let section = LectureSection::new(code, Some(" ".to_string()));
+        assert_eq!(section.division(), None);
+        assert_eq!(section.to_string(), "2150001");
+    }
+}
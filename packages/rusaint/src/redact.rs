@@ -0,0 +1,131 @@
+use std::fmt::{self, Debug};
+
+use serde::{Serialize, Serializer};
+
+/// 민감한 개인정보를 가린 사본을 만들 수 있는 데이터
+pub trait Redact: Sized {
+    /// 민감한 필드를 가린 사본을 반환합니다.
+    fn redacted(&self) -> Self;
+}
+
+impl<T: Redact> Redact for Option<T> {
+    fn redacted(&self) -> Self {
+        self.as_ref().map(Redact::redacted)
+    }
+}
+
+impl<T: Redact> Redact for Vec<T> {
+    fn redacted(&self) -> Self {
+        self.iter().map(Redact::redacted).collect()
+    }
+}
+
+/// 직렬화하거나 [`Debug`]로 출력할 때 민감한 필드를 가리는 래퍼
+///
+/// ```
+/// # use rusaint::redact::{Redact, Redacted};
+/// # #[derive(Debug)]
+/// # struct Account(String);
+/// # impl Redact for Account {
+/// #     fn redacted(&self) -> Self { Account(rusaint::redact::mask_digits(&self.0, 4)) }
+/// # }
+/// let account = Account("110-123-456789".to_string());
+/// assert_eq!(format!("{:?}", Redacted(&account)), "Account(\"***-***-**6789\")");
+/// ```
+pub struct Redacted<'a, T>(pub &'a T);
+
+impl<T: Redact + Debug> Debug for Redacted<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.redacted().fmt(f)
+    }
+}
+
+impl<T: Redact + Serialize> Serialize for Redacted<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.redacted().serialize(serializer)
+    }
+}
+
+/// 이름의 첫 글자와 (세 글자 이상이라면) 마지막 글자만 남기고 가립니다. (e.g. "홍길동" -> "홍*동")
+pub fn mask_name(value: &str) -> String {
+    let chars: Vec<char> = value.trim().chars().collect();
+    match chars.len() {
+        0 => String::new(),
+        1 | 2 => format!("{}{}", chars[0], "*".repeat(chars.len() - 1)),
+        len => format!("{}{}{}", chars[0], "*".repeat(len - 2), chars[len - 1]),
+    }
+}
+
+/// 마지막 `keep`개의 숫자만 남기고 숫자를 가립니다. 구분자(e.g. `-`)는 유지합니다.
+pub fn mask_digits(value: &str, keep: usize) -> String {
+    let digits = value.chars().filter(char::is_ascii_digit).count();
+    let mut seen = 0;
+    value
+        .chars()
+        .map(|c| {
+            if !c.is_ascii_digit() {
+                return c;
+            }
+            seen += 1;
+            if seen > digits.saturating_sub(keep) {
+                c
+            } else {
+                '*'
+            }
+        })
+        .collect()
+}
+
+/// 전화번호의 마지막 네 자리만 남기고 가립니다.
+pub fn mask_phone(value: &str) -> String {
+    mask_digits(value, 4)
+}
+
+/// 이메일 주소의 첫 글자와 도메인만 남기고 가립니다. (e.g. "student@ssu.ac.kr" -> "s***@ssu.ac.kr")
+pub fn mask_email(value: &str) -> String {
+    match value.trim().split_once('@') {
+        Some((local, domain)) => {
+            let first = local.chars().next().map(String::from).unwrap_or_default();
+            format!("{first}***@{domain}")
+        }
+        None => mask_name(value),
+    }
+}
+
+/// 주소의 앞 두 단어(e.g. 시/도, 시/군/구)만 남기고 가립니다.
+pub fn mask_address(value: &str) -> String {
+    let words: Vec<&str> = value.split_whitespace().collect();
+    if words.len() <= 2 {
+        return "***".to_string();
+    }
+    format!("{} {} ***", words[0], words[1])
+}
+
+/// 값 전체를 가립니다.
+pub fn mask_all(value: &str) -> String {
+    if value.is_empty() {
+        String::new()
+    } else {
+        "***".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_values() {
+        assert_eq!(mask_name("홍길동"), "홍*동");
+        assert_eq!(mask_name("김철"), "김*");
+        assert_eq!(mask_phone("010-1234-5678"), "***-****-5678");
+        assert_eq!(mask_digits("06978", 2), "***78");
+        assert_eq!(mask_email("student@ssu.ac.kr"), "s***@ssu.ac.kr");
+        assert_eq!(
+            mask_address("서울특별시 동작구 상도로 369"),
+            "서울특별시 동작구 ***"
+        );
+        assert_eq!(mask_address("서울특별시"), "***");
+        assert_eq!(mask_all(""), "");
+    }
+}
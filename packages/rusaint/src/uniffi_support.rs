@@ -1,3 +1,7 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::model::LectureCode;
+
 #[derive(uniffi::Record)]
 /// uniffi 지원을 위한 u32 Pair입니다.
 pub struct UnsignedIntPair {
@@ -15,3 +19,30 @@ uniffi::custom_type!(U32Pair, UnsignedIntPair, {
     },
     try_lift: |val| Ok((val.first, val.second))
 });
+
+// 날짜는 ISO 8601 형식(YYYY-MM-DD)의 문자열로 전달됩니다.
+uniffi::custom_type!(NaiveDate, String, {
+    remote,
+    lower: |date| date.format("%Y-%m-%d").to_string(),
+    try_lift: |val| Ok(NaiveDate::parse_from_str(&val, "%Y-%m-%d")?)
+});
+
+// 일시는 ISO 8601 형식(YYYY-MM-DDTHH:MM:SS)의 문자열로 전달됩니다.
+uniffi::custom_type!(NaiveDateTime, String, {
+    remote,
+    lower: |date_time| date_time.format("%Y-%m-%dT%H:%M:%S").to_string(),
+    try_lift: |val| Ok(NaiveDateTime::parse_from_str(&val, "%Y-%m-%dT%H:%M:%S")?)
+});
+
+// 시각은 HH:MM:SS 형식의 문자열로 전달됩니다.
+uniffi::custom_type!(NaiveTime, String, {
+    remote,
+    lower: |time| time.format("%H:%M:%S").to_string(),
+    try_lift: |val| Ok(NaiveTime::parse_from_str(&val, "%H:%M:%S")?)
+});
+
+// 과목번호는 문자열로 전달됩니다.
+uniffi::custom_type!(LectureCode, String, {
+    lower: |code| code.into(),
+    try_lift: |val| Ok(LectureCode::new(val))
+});
@@ -54,3 +54,13 @@ async fn no_chapel() {
     tracing::info!("{:?}", info);
     tracing::info!("{:?}", info);
 }
+
+#[tokio::test]
+#[traced_test]
+async fn chapel_history() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let history = app.history(*TARGET_YEAR - 1).await.unwrap();
+    assert!(!history.records().is_empty());
+    tracing::info!("{:?}", history);
+}
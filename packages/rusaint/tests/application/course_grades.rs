@@ -94,7 +94,7 @@ async fn classes_with_detail() {
         .await
         .unwrap();
     tracing::info!("{:?}", detail);
-    assert!(!detail.is_empty());
+    assert!(!detail.raw().is_empty());
 }
 
 #[tokio::test]
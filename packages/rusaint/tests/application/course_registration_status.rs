@@ -54,3 +54,21 @@ async fn lectures() {
     assert!(lectures.iter().all(|lecture| !lecture.sm_objid.is_empty()));
     assert!(lectures.iter().all(|lecture| !lecture.se_objid.is_empty()));
 }
+
+#[tokio::test]
+#[traced_test]
+async fn history() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let (year, _) = app.get_selected_semester().unwrap();
+    let history = app.history(year).await.unwrap();
+    tracing::info!("History: {:?}", history);
+
+    assert!(history.total_lectures() > 0);
+    assert!(
+        history
+            .lectures()
+            .filter(|lecture| !lecture.registration_date.is_empty())
+            .all(|lecture| lecture.registered_at.is_some())
+    );
+}
@@ -251,7 +251,7 @@ async fn lecture_detail() {
         .collect();
     assert!(!lectures.is_empty(), "Should have at least one lecture");
     let first_lecture = &lectures[0];
-    let code = first_lecture.code.as_str();
+    let code = &first_lecture.code;
     tracing::info!("Getting detail for lecture code: {}", code);
     let detail = app.lecture_detail(code).await.unwrap();
     tracing::info!("{:?}", detail);
@@ -278,7 +278,7 @@ async fn lecture_syllabus() {
         .iter()
         .find(|l| l.syllabus.is_some())
         .expect("Should have at least one lecture with a syllabus");
-    let code = lecture_with_syllabus.code.as_str();
+    let code = &lecture_with_syllabus.code;
     tracing::info!("Testing syllabus for lecture code: {}", code);
     let syllabus = app.lecture_syllabus(code).await.unwrap();
     tracing::info!("Syllabus: {:#?}", syllabus);
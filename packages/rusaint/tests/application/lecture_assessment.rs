@@ -21,3 +21,38 @@ async fn lecture_assessment() {
     assert_eq!(info.len(), 29);
     tracing::info!("{} results: {:?}", info.len(), info);
 }
+
+#[tokio::test]
+#[traced_test]
+async fn aggregate_assessments() {
+    let session = get_session().await.unwrap().clone();
+    let mut app = USaintClientBuilder::new()
+        .session(session)
+        .build_into::<LectureAssessmentApplication>()
+        .await
+        .unwrap();
+    let aggregation = app
+        .aggregate_assessments(2022, 2023, Some("마케팅"), None, None)
+        .await
+        .unwrap();
+    assert!(!aggregation.professors().is_empty());
+    tracing::info!("{:?}", aggregation);
+}
+
+#[tokio::test]
+#[traced_test]
+async fn assessment_detail() {
+    let session = get_session().await.unwrap().clone();
+    let mut app = USaintClientBuilder::new()
+        .session(session)
+        .build_into::<LectureAssessmentApplication>()
+        .await
+        .unwrap();
+    let info = app
+        .find_assessments(2023, SemesterType::Two, Some("마케팅"), None, None)
+        .await
+        .unwrap();
+    let detail = app.assessment_detail(&info[0]).await.unwrap();
+    assert!(!detail.questions().is_empty());
+    tracing::info!("{:?}", detail);
+}
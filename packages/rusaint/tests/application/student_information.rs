@@ -1,8 +1,10 @@
 use crate::get_session;
 use lazy_static::lazy_static;
 use rusaint::{
-    RusaintError, application::student_information::StudentInformationApplication,
+    RusaintError,
+    application::student_information::{StudentInformationApplication, model::ContactUpdate},
     client::USaintClientBuilder,
+    redact::Redacted,
 };
 use std::sync::{Arc, OnceLock};
 use tokio::sync::{Mutex, RwLock};
@@ -38,7 +40,43 @@ async fn general() {
     let lock = get_app().await.unwrap();
     let app = lock.read().await;
     let student_info = app.general().unwrap();
-    tracing::info!("{:?}", student_info);
+    tracing::info!("{:?}", Redacted(&student_info));
+}
+
+#[tokio::test]
+#[traced_test]
+async fn profile() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let profile = app.profile().await;
+    tracing::info!("{:?}", Redacted(&profile));
+    assert!(profile.general().is_some());
+    assert!(profile.is_complete(), "{:?}", profile.errors());
+}
+
+#[tokio::test]
+#[traced_test]
+async fn photo() {
+    let lock = get_app().await.unwrap();
+    let app = lock.read().await;
+    let photo = app.photo().await.unwrap();
+    assert!(!photo.data().is_empty());
+    tracing::info!("{} ({} bytes)", photo.mime_type(), photo.data().len());
+}
+
+#[tokio::test]
+#[traced_test]
+async fn update_contact_dry_run() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let update = ContactUpdate {
+        email: Some("rusaint-test@soongsil.ac.kr".to_string()),
+        ..Default::default()
+    };
+    let result = app.update_contact(&update, true).await.unwrap();
+    assert!(result.dry_run());
+    assert!(result.messages().is_empty());
+    tracing::info!("{:?}", Redacted(&result));
 }
 
 #[tokio::test]
@@ -80,7 +118,7 @@ async fn work() {
     let lock = get_app().await.unwrap();
     let mut app = lock.write().await;
     let student_info = app.work().await.unwrap();
-    tracing::info!("{:?}", student_info);
+    tracing::info!("{:?}", Redacted(&student_info));
 }
 
 #[tokio::test]
@@ -89,7 +127,7 @@ async fn family() {
     let lock = get_app().await.unwrap();
     let mut app = lock.write().await;
     let student_info = app.family().await.unwrap();
-    tracing::info!("{:?}", student_info);
+    tracing::info!("{:?}", Redacted(&student_info));
 }
 
 #[tokio::test]
@@ -116,7 +154,7 @@ async fn bank_account() {
     let lock = get_app().await.unwrap();
     let mut app = lock.write().await;
     let student_info = app.bank_account().await.unwrap();
-    tracing::info!("{:?}", student_info);
+    tracing::info!("{:?}", Redacted(&student_info));
 }
 
 #[tokio::test]
@@ -134,5 +172,5 @@ async fn research_bank_account() {
     let lock = get_app().await.unwrap();
     let mut app = lock.write().await;
     let student_info = app.research_bank_account().await.unwrap();
-    tracing::info!("{:?}", student_info);
+    tracing::info!("{:?}", Redacted(&student_info));
 }